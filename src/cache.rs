@@ -0,0 +1,61 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use anyhow::Result;
+
+use crate::model::ScoreboardResponse;
+
+/// The last scoreboard fetched for each league, so there's something to show at
+/// startup before the first fetch finishes (or when offline).
+pub struct ScoreboardCache {
+    dir: PathBuf,
+}
+
+impl ScoreboardCache {
+    /// `~/.cache/nfl-tui` (or the platform equivalent).
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("nfl-tui"))
+    }
+
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    fn path(&self, league: &str) -> PathBuf {
+        self.dir.join(format!("scoreboard-{}.json", league))
+    }
+
+    pub fn save(&self, league: &str, data: &ScoreboardResponse) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(league), serde_json::to_vec(data)?)?;
+        Ok(())
+    }
+
+    /// The cached scoreboard and when it was saved. A missing or unreadable cache is just `None`.
+    pub fn load(&self, league: &str) -> Option<(ScoreboardResponse, SystemTime)> {
+        let path = self.path(league);
+        let saved = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+        let data = serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+        Some((data, saved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let dir = std::env::temp_dir().join(format!("nfl-tui-cache-{}", std::process::id()));
+        let cache = ScoreboardCache::new(dir.clone());
+        assert!(cache.load("nfl").is_none());
+
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        cache.save("nfl", &data).unwrap();
+
+        let (loaded, _) = cache.load("nfl").expect("Cache should load");
+        assert_eq!(loaded, data);
+        assert!(cache.load("college-football").is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+use ratatui::{buffer::Buffer, style::Color};
+
+/// How many colors the terminal can show. Team colors are 24-bit, so anything less
+/// needs them mapped to the nearest palette entry or they come out wrong (or not at all).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+// xterm's defaults for the 16 basic colors
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+// The steps of each channel in the 6x6x6 cube at 16-231
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl ColorSupport {
+    /// Goes by `COLORTERM`, then `TERM`, the way most terminal apps do.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        Self::from_env(&colorterm, &term)
+    }
+
+    fn from_env(colorterm: &str, term: &str) -> Self {
+        if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Ansi16
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorSupport::TrueColor => "24-bit",
+            ColorSupport::Ansi256 => "256",
+            ColorSupport::Ansi16 => "16",
+        }
+    }
+
+    /// The closest color this terminal can show; non-RGB colors pass through.
+    pub fn adapt(self, color: Color) -> Color {
+        let Color::Rgb(r, g, b) = color else {
+            return color;
+        };
+        match self {
+            ColorSupport::TrueColor => color,
+            ColorSupport::Ansi256 => Color::Indexed(nearest_256(r, g, b)),
+            ColorSupport::Ansi16 => nearest_16(r, g, b),
+        }
+    }
+
+    /// Maps every RGB color in a drawn frame, so the widgets can stay 24-bit.
+    pub fn adapt_buffer(self, buf: &mut Buffer) {
+        if self == ColorSupport::TrueColor {
+            return;
+        }
+        for cell in &mut buf.content {
+            cell.fg = self.adapt(cell.fg);
+            cell.bg = self.adapt(cell.bg);
+        }
+    }
+}
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16.iter().min_by_key(|(_, rgb)| distance(*rgb, (r, g, b))).map(|(c, _)| *c).unwrap_or(Color::Reset)
+}
+
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let step = |v: u8| CUBE_LEVELS.iter().enumerate().min_by_key(|(_, l)| (**l as i32 - v as i32).abs()).map(|(i, _)| i).unwrap_or(0);
+    let (ri, gi, bi) = (step(r), step(g), step(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    // The grayscale ramp at 232-255 is finer than the cube's grays
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray_index = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray = 8 + 10 * gray_index;
+
+    if distance((gray, gray, gray), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(ColorSupport::from_env("truecolor", "xterm-256color"), ColorSupport::TrueColor);
+        assert_eq!(ColorSupport::from_env("", "xterm-256color"), ColorSupport::Ansi256);
+        assert_eq!(ColorSupport::from_env("", "linux"), ColorSupport::Ansi16);
+    }
+
+    #[test]
+    fn test_adapt() {
+        // Chiefs red
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Rgb(0xe3, 0x18, 0x37)), Color::Indexed(161));
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Rgb(0xe3, 0x18, 0x37)), Color::Red);
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Rgb(128, 128, 128)), Color::Indexed(244));
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Yellow), Color::Yellow);
+        assert_eq!(ColorSupport::TrueColor.adapt(Color::Rgb(1, 2, 3)), Color::Rgb(1, 2, 3));
+    }
+}
@@ -0,0 +1,77 @@
+use anyhow::{bail, Context, Result};
+
+use crate::data::league_slug;
+
+/// What the `:` command line can do, beyond the single-key bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// ESPN's league slug: `nfl` or `college-football`.
+    League(String),
+    Week(u32),
+    Interval(u64),
+    /// Only live games, or everything.
+    Filter(bool),
+    Quit,
+}
+
+impl Command {
+    /// Parses a line like `league ncaa`, `week 12`, `interval 10`, `filter live` or `q`.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut words = input.split_whitespace();
+        let Some(name) = words.next() else {
+            bail!("Empty command");
+        };
+        let arg = words.next();
+        if words.next().is_some() {
+            bail!("Too many arguments to '{}'", name);
+        }
+
+        let command = match (name, arg) {
+            ("q" | "quit", None) => Command::Quit,
+            ("league", Some(league)) => match league_slug(league) {
+                Some(slug) => Command::League(slug.to_string()),
+                None => bail!("Unknown league '{}' (nfl or ncaa)", league),
+            },
+            ("week", Some(week)) => match week.parse().with_context(|| format!("Not a week number: '{}'", week))? {
+                0 => bail!("Weeks start at 1"),
+                week => Command::Week(week),
+            },
+            ("interval", Some(secs)) => match secs.parse().with_context(|| format!("Not a number of seconds: '{}'", secs))? {
+                0 => bail!("The interval must be at least 1 second"),
+                secs => Command::Interval(secs),
+            },
+            ("filter", Some("live")) => Command::Filter(true),
+            ("filter", Some("all" | "none" | "off")) => Command::Filter(false),
+            ("filter", Some(other)) => bail!("Unknown filter '{}' (live or all)", other),
+            ("league" | "week" | "interval" | "filter", None) => bail!("'{}' needs an argument", name),
+            _ => bail!("Unknown command '{}'", name),
+        };
+        Ok(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Command::parse("league ncaa").unwrap(), Command::League("college-football".to_string()));
+        assert_eq!(Command::parse(" week 12 ").unwrap(), Command::Week(12));
+        assert_eq!(Command::parse("interval 10").unwrap(), Command::Interval(10));
+        assert_eq!(Command::parse("filter live").unwrap(), Command::Filter(true));
+        assert_eq!(Command::parse("filter all").unwrap(), Command::Filter(false));
+        assert_eq!(Command::parse("q").unwrap(), Command::Quit);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Command::parse("").is_err());
+        assert!(Command::parse("week").is_err());
+        assert!(Command::parse("week twelve").is_err());
+        assert!(Command::parse("week 0").is_err());
+        assert!(Command::parse("league xfl").is_err());
+        assert!(Command::parse("quit now").is_err());
+        assert!(Command::parse("rewind").is_err());
+    }
+}
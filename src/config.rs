@@ -0,0 +1,163 @@
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::keymap::{Action, KeySpec};
+use crate::logo::LogoStyle;
+use crate::notify::{CloseGameConfig, HookConfig, NtfyConfig, SoundConfig, WebhookConfig};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Overrides for the default key bindings, keyed by action name.
+    pub keys: HashMap<Action, KeySpec>,
+    /// Team abbreviations that are always treated as favorites.
+    pub favorites: Vec<String>,
+    /// Show the betting line next to upcoming games in the sidebar.
+    pub sidebar_odds: bool,
+    /// Start with the scrolling score ticker shown.
+    pub ticker: bool,
+    /// Seconds each live game stays up in auto-rotate mode.
+    pub rotate_secs: Option<u64>,
+    /// Where to POST alerts for favorite teams' games.
+    pub webhook: Option<WebhookConfig>,
+    /// ntfy topic to publish the same alerts to.
+    pub ntfy: Option<NtfyConfig>,
+    /// Shell command to run on score changes, turnovers and finals.
+    pub on_event: Option<HookConfig>,
+    /// Ring the terminal bell (or run a sound command) on favorite teams' scores and finals.
+    pub sound: Option<SoundConfig>,
+    /// When to flag a game as close in the fourth quarter.
+    pub close_game: CloseGameConfig,
+    /// Minutes before a favorite team's kickoff to remind you; off when unset.
+    pub kickoff_reminder: Option<u32>,
+    /// Replaces `https://site.api.espn.com/apis`, e.g. to use a caching proxy or mock server.
+    pub api_url: Option<String>,
+    /// Leagues to follow at once, as tabs (`nfl`, `ncaa`).
+    pub leagues: Vec<String>,
+    /// Use text instead of emoji, for fonts that draw 🏈 as a double-width box.
+    pub no_emoji: bool,
+    /// What marks the team with the ball; overrides `no_emoji`, and `""` hides it.
+    pub possession_marker: Option<String>,
+    /// How to draw team logos: `auto`, `kitty`, `iterm2`, `halfblock` or `braille`.
+    pub logo_style: LogoStyle,
+}
+
+impl Config {
+    /// `~/.config/nfl-tui/config.toml` (or the platform equivalent).
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("nfl-tui").join("config.toml"))
+    }
+
+    /// Loads the config from `path`, or the default location when `None`.
+    /// A missing file is not an error; everything has a default.
+    pub fn load(path: Option<PathBuf>) -> Result<Self> {
+        let explicit = path.is_some();
+        let Some(path) = path.or_else(Self::default_path) else {
+            return Ok(Self::default());
+        };
+        if !explicit && !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// The possession marker to draw next to the team with the ball.
+    pub fn possession_marker(&self) -> &str {
+        match &self.possession_marker {
+            Some(marker) => marker,
+            None if self.no_emoji => "●",
+            None => "🏈",
+        }
+    }
+
+    /// The API base URL to use, if not ESPN's. `NFL_TUI_API_URL` beats the config file.
+    pub fn api_url(&self) -> Option<String> {
+        std::env::var("NFL_TUI_API_URL")
+            .ok()
+            .filter(|url| !url.is_empty())
+            .or_else(|| self.api_url.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        let config: Config = toml::from_str(
+            r#"
+            [keys]
+            quit = "Q"
+            next = ["n", "Down"]
+            toggle_standings = "g s"
+            "#,
+        )
+        .expect("Failed to parse config");
+        assert_eq!(config.keys.len(), 3);
+        assert!(matches!(config.keys.get(&Action::Next), Some(KeySpec::Many(v)) if v.len() == 2));
+    }
+
+    #[test]
+    fn test_parse_leagues() {
+        let config: Config = toml::from_str(r#"leagues = ["nfl", "ncaa"]"#).expect("Failed to parse config");
+        assert_eq!(config.leagues, vec!["nfl", "ncaa"]);
+        assert!(Config::default().leagues.is_empty());
+    }
+
+    #[test]
+    fn test_parse_logo_style() {
+        let config: Config = toml::from_str(r#"logo_style = "iterm2""#).expect("Failed to parse config");
+        assert_eq!(config.logo_style, LogoStyle::Iterm2);
+        assert_eq!(Config::default().logo_style, LogoStyle::Auto);
+    }
+
+    #[test]
+    fn test_possession_marker() {
+        assert_eq!(Config::default().possession_marker(), "🏈");
+        let config: Config = toml::from_str("no_emoji = true").expect("Failed to parse config");
+        assert_eq!(config.possession_marker(), "●");
+        let config: Config = toml::from_str(r#"possession_marker = "<>""#).expect("Failed to parse config");
+        assert_eq!(config.possession_marker(), "<>");
+    }
+
+    #[test]
+    fn test_parse_sound() {
+        let config: Config = toml::from_str("[sound]").expect("Failed to parse config");
+        let sound = config.sound.expect("Missing sound");
+        assert_eq!(sound.command, None);
+        assert_eq!(sound.events.len(), 2);
+        assert!(Config::default().sound.is_none());
+    }
+
+    #[test]
+    fn test_parse_close_game() {
+        let config: Config = toml::from_str("[close_game]\nminutes = 2").expect("Failed to parse config");
+        assert_eq!(config.close_game, CloseGameConfig { minutes: 2, margin: 8 });
+    }
+
+    #[test]
+    fn test_parse_kickoff_reminder() {
+        let config: Config = toml::from_str("kickoff_reminder = 15").expect("Failed to parse config");
+        assert_eq!(config.kickoff_reminder, Some(15));
+        assert_eq!(Config::default().kickoff_reminder, None);
+    }
+
+    #[test]
+    fn test_parse_ntfy_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            [ntfy]
+            topic = "my-scores"
+            "#,
+        )
+        .expect("Failed to parse config");
+        let ntfy = config.ntfy.expect("Missing ntfy");
+        assert_eq!(ntfy.server, "https://ntfy.sh");
+        assert_eq!(ntfy.events.len(), 4);
+    }
+}
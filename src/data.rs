@@ -1,35 +1,942 @@
-use crate::model::ScoreboardResponse;
+use std::{collections::HashMap, fmt, future::Future, sync::Mutex, time::Duration};
+
+use crate::model::{Event, News, Rankings, Schedule, ScoreboardResponse, SeriesEvent, Standings, Summary};
 use anyhow::Result;
-use reqwest::Client;
+use reqwest::{header, Client, Proxy, StatusCode};
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+
+// Optional scoreboard filters; an empty query returns ESPN's "current" slate
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScoreboardQuery {
+    pub week: Option<u32>,
+    pub dates: Option<String>,
+    // College football conference; see NCAA_GROUPS
+    pub groups: Option<String>,
+    pub season_type: Option<SeasonType>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum SeasonType {
+    Preseason,
+    Regular,
+    Postseason,
+}
+
+impl SeasonType {
+    /// ESPN's `seasontype` number.
+    pub fn number(self) -> u32 {
+        match self {
+            SeasonType::Preseason => 1,
+            SeasonType::Regular => 2,
+            SeasonType::Postseason => 3,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SeasonType::Preseason => "Preseason",
+            SeasonType::Regular => "Regular Season",
+            SeasonType::Postseason => "Postseason",
+        }
+    }
+
+    /// The runtime toggle's order; `None` is whatever ESPN considers current.
+    pub fn cycle(current: Option<SeasonType>) -> Option<SeasonType> {
+        match current {
+            None => Some(SeasonType::Preseason),
+            Some(SeasonType::Preseason) => Some(SeasonType::Regular),
+            Some(SeasonType::Regular) => Some(SeasonType::Postseason),
+            Some(SeasonType::Postseason) => None,
+        }
+    }
+}
+
+/// ESPN's college football `groups` ids. Without one, the scoreboard shows the Top 25.
+pub const NCAA_GROUPS: &[(&str, &str)] = &[
+    ("80", "All FBS"),
+    ("1", "ACC"),
+    ("151", "American"),
+    ("4", "Big 12"),
+    ("5", "Big Ten"),
+    ("12", "Conference USA"),
+    ("18", "FBS Independents"),
+    ("15", "MAC"),
+    ("17", "Mountain West"),
+    ("9", "Pac-12"),
+    ("8", "SEC"),
+    ("37", "Sun Belt"),
+    ("81", "FCS"),
+];
+
+/// "SEC" for "8"; unknown ids are shown as-is.
+pub fn group_name(id: &str) -> &str {
+    NCAA_GROUPS.iter().find(|(g, _)| *g == id).map(|(_, name)| *name).unwrap_or(id)
+}
+
+/// ESPN's slug for a league as typed: `nfl`, or `ncaa` (and a few aliases) for college football.
+pub fn league_slug(name: &str) -> Option<&'static str> {
+    match name.to_lowercase().as_str() {
+        "nfl" => Some("nfl"),
+        "ncaa" | "cfb" | "college" | "college-football" => Some("college-football"),
+        _ => None,
+    }
+}
+
+/// "NFL" or "NCAA", for display.
+pub fn league_name(slug: &str) -> &'static str {
+    if slug == "college-football" {
+        "NCAA"
+    } else {
+        "NFL"
+    }
+}
+
+/// Checks a `--date` and puts it the way ESPN's `dates` parameter wants it: "20251225"
+/// (dashes are allowed and dropped).
+pub fn parse_date(input: &str) -> Result<String, String> {
+    let digits = input.replace('-', "");
+    chrono::NaiveDate::parse_from_str(&digits, "%Y%m%d").map_err(|_| format!("'{}' isn't a date like 20251225", input))?;
+    Ok(digits)
+}
+
+impl ScoreboardQuery {
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![];
+        if let Some(week) = self.week {
+            params.push(("week", week.to_string()));
+        }
+        if let Some(dates) = &self.dates {
+            params.push(("dates", dates.clone()));
+        }
+        if let Some(groups) = &self.groups {
+            params.push(("groups", groups.clone()));
+        }
+        if let Some(season_type) = self.season_type {
+            params.push(("seasontype", season_type.number().to_string()));
+        }
+        params
+    }
+}
+
+/// NFL postseason rounds by their week number in the postseason scoreboard (week 4 is the Pro Bowl).
+pub const PLAYOFF_ROUNDS: &[(u32, &str)] = &[(1, "Wild Card"), (2, "Divisional"), (3, "Conference"), (5, "Super Bowl")];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bracket {
+    /// Games in each of [`PLAYOFF_ROUNDS`]; empty until the matchups are set.
+    pub rounds: Vec<(&'static str, Vec<Event>)>,
+    /// Conference and seed by team abbreviation, from the standings.
+    pub seeds: HashMap<String, (String, u32)>,
+}
+
+/// Fetches every playoff round plus the standings for seeds. Rounds (or standings) that
+/// haven't changed since `previous` come back 304 and are carried over from it.
+pub async fn fetch_bracket(source: &impl ScoreboardSource, league: &str, previous: Option<&Bracket>) -> Result<Bracket> {
+    let mut rounds = vec![];
+    for (i, &(week, name)) in PLAYOFF_ROUNDS.iter().enumerate() {
+        let query = ScoreboardQuery { week: Some(week), season_type: Some(SeasonType::Postseason), ..Default::default() };
+        let events = match source.fetch_scoreboard(league, &query).await {
+            Ok(data) => data.events,
+            Err(err) if err.is::<NotModified>() => match previous.and_then(|b| b.rounds.get(i)) {
+                Some((_, events)) => events.clone(),
+                None => return Err(err),
+            },
+            Err(err) => return Err(err),
+        };
+        rounds.push((name, events));
+    }
+    // Seeds are nice to have; the bracket still works without them
+    let seeds = match source.fetch_standings(league).await {
+        Ok(standings) => standings.playoff_seeds(),
+        Err(_) => previous.map(|b| b.seeds.clone()).unwrap_or_default(),
+    };
+    Ok(Bracket { rounds, seeds })
+}
+
+/// Every FBS bowl, CFP games included; ESPN files them all under week 1 of the college postseason.
+pub fn bowls_query() -> ScoreboardQuery {
+    ScoreboardQuery {
+        week: Some(1),
+        groups: Some("80".to_string()),
+        season_type: Some(SeasonType::Postseason),
+        ..Default::default()
+    }
+}
+
+// How many past meetings the head-to-head panel shows, and how far back to look for them
+pub const HEAD_TO_HEAD_GAMES: usize = 5;
+const HEAD_TO_HEAD_SEASONS: i32 = 8;
+
+/// Cache key for a matchup, the same whichever team is at home.
+pub fn matchup_key(team_id: &str, opponent_id: &str) -> String {
+    if team_id < opponent_id {
+        format!("{}-{}", team_id, opponent_id)
+    } else {
+        format!("{}-{}", opponent_id, team_id)
+    }
+}
+
+/// The most recent meetings between two teams, newest first, from one team's schedules
+/// working back from `season`. Teams from different divisions can go years between games,
+/// so this gives up after a few seasons rather than finding all five.
+pub async fn fetch_head_to_head(source: &impl ScoreboardSource, league: &str, team_id: &str, opponent_id: &str, season: i32) -> Result<Vec<SeriesEvent>> {
+    let mut meetings = vec![];
+    // A season with no schedule (say, before a program moved up) shouldn't sink the rest
+    let mut last_err = None;
+    let mut fetched_any = false;
+    for year in (season - HEAD_TO_HEAD_SEASONS + 1..=season).rev() {
+        for season_type in [SeasonType::Postseason, SeasonType::Regular] {
+            let schedule = match source.fetch_schedule(league, team_id, year, season_type).await {
+                Ok(schedule) => schedule,
+                Err(err) => {
+                    warn!(%team_id, season = year, ?season_type, %err, "schedule fetch failed");
+                    last_err = Some(err);
+                    continue;
+                }
+            };
+            fetched_any = true;
+            let mut found: Vec<SeriesEvent> = schedule.events.iter().filter_map(|e| e.meeting(opponent_id)).collect();
+            // A game can be listed under both season types
+            found.retain(|m| !meetings.iter().any(|seen: &SeriesEvent| seen.id == m.id));
+            found.sort_by(|a, b| b.date.cmp(&a.date));
+            meetings.extend(found);
+        }
+        if meetings.len() >= HEAD_TO_HEAD_GAMES {
+            break;
+        }
+    }
+    if let Some(err) = last_err.filter(|_| !fetched_any) {
+        return Err(err);
+    }
+    meetings.truncate(HEAD_TO_HEAD_GAMES);
+    Ok(meetings)
+}
+
+const ESPN_API: &str = "https://site.api.espn.com/apis";
+// reqwest never times out on its own, and a hung request would stop updates without an error
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long to wait before retrying after `failures` failed fetches in a row:
+/// 2s, 4s, 8s, ... up to a minute, or the refresh interval if that's longer.
+pub fn backoff(failures: u32, interval_secs: u64) -> Duration {
+    let secs = 1u64 << failures.clamp(1, 16);
+    Duration::from_secs(secs.min(interval_secs.max(60)))
+}
+
+/// Where scores, summaries, news, standings and polls come from. [`DataClient`] is the real
+/// one; tests use [`MockSource`].
+pub trait ScoreboardSource: Send + Sync {
+    fn fetch_scoreboard(&self, league: &str, query: &ScoreboardQuery) -> impl Future<Output = Result<ScoreboardResponse>> + Send;
+    fn fetch_summary(&self, league: &str, event_id: &str) -> impl Future<Output = Result<Summary>> + Send;
+    fn fetch_news(&self, league: &str) -> impl Future<Output = Result<News>> + Send;
+    fn fetch_standings(&self, league: &str) -> impl Future<Output = Result<Standings>> + Send;
+    fn fetch_rankings(&self, league: &str) -> impl Future<Output = Result<Rankings>> + Send;
+    fn fetch_schedule(&self, league: &str, team_id: &str, season: i32, season_type: SeasonType) -> impl Future<Output = Result<Schedule>> + Send;
+}
+
+/// Returned by [`DataClient`] when ESPN answers 304: nothing changed since the last fetch
+/// of that URL, so there's nothing new to parse or show.
+#[derive(Debug)]
+pub struct NotModified;
+
+impl fmt::Display for NotModified {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "not modified since the last fetch")
+    }
+}
+
+impl std::error::Error for NotModified {}
+
+/// A response that arrived but didn't fit the model, with the JSON path of the field
+/// that didn't (e.g. `week.number`).
+#[derive(Debug)]
+pub struct BadResponse {
+    pub path: String,
+    pub source: serde_json::Error,
+}
+
+impl fmt::Display for BadResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected data at {}: {}", self.path, self.source)
+    }
+}
+
+impl std::error::Error for BadResponse {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(body: &str) -> Result<T, BadResponse> {
+    serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_str(body)).map_err(|err| BadResponse {
+        path: err.path().to_string(),
+        source: err.into_inner(),
+    })
+}
+
+// What to send back as If-None-Match / If-Modified-Since next time
+#[derive(Debug, Default, Clone)]
+struct Validators {
+    etag: Option<header::HeaderValue>,
+    last_modified: Option<header::HeaderValue>,
+}
 
 pub struct DataClient {
     client: Client,
+    base_url: String,
+    validators: Mutex<HashMap<String, Validators>>,
 }
 
 impl DataClient {
     pub fn new() -> Self {
+        Self::with_base_url(ESPN_API)
+    }
+
+    /// Points the client somewhere other than ESPN, e.g. a local mock server.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
         Self {
-            client: Client::new(),
+            // Only fails if the TLS backend can't start, as with `Client::new`
+            client: Client::builder().timeout(REQUEST_TIMEOUT).build().expect("failed to build HTTP client"),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            validators: Mutex::new(HashMap::new()),
         }
     }
 
-    pub async fn fetch_scoreboard(&self, league: &str) -> Result<ScoreboardResponse> {
-        let url = format!("https://site.api.espn.com/apis/site/v2/sports/football/{}/scoreboard", league);
-        let resp = self.client.get(&url).send().await?;
-        let data = resp.json::<ScoreboardResponse>().await?;
+    /// Sends every request through `proxy` (an `http://` or `https://` URL) instead of
+    /// whatever `HTTP_PROXY`/`HTTPS_PROXY` say.
+    pub fn with_proxy(self, proxy: &str) -> Result<Self> {
+        let client = Client::builder().timeout(REQUEST_TIMEOUT).proxy(Proxy::all(proxy)?).build()?;
+        Ok(Self { client, ..self })
+    }
+
+    /// The underlying HTTP client, for other requests that should share its proxy settings.
+    pub fn http(&self) -> Client {
+        self.client.clone()
+    }
+
+    /// Downloads an arbitrary URL, like a team logo.
+    pub async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        let resp = self.client.get(url).send().await?.error_for_status()?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// Forgets every ETag/Last-Modified, so the next fetches return full responses
+    /// (e.g. after the UI has thrown its copy of the data away).
+    pub fn reset_validators(&self) {
+        self.validators.lock().unwrap().clear();
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str, query: &[(&str, String)]) -> Result<T> {
+        let url = format!("{}/{}", self.base_url, path);
+        let mut request = self.client.get(&url).query(query).build()?;
+        let key = request.url().to_string();
+
+        let known = self.validators.lock().unwrap().get(&key).cloned().unwrap_or_default();
+        if let Some(etag) = known.etag {
+            request.headers_mut().insert(header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = known.last_modified {
+            request.headers_mut().insert(header::IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let started = std::time::Instant::now();
+        let resp = self.client.execute(request).await.inspect_err(|err| warn!(url = %key, %err, "request failed"))?;
+        debug!(url = %key, status = resp.status().as_u16(), elapsed_ms = started.elapsed().as_millis() as u64, "response");
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            return Err(NotModified.into());
+        }
+        let resp = resp.error_for_status()?;
+        let fresh = Validators {
+            etag: resp.headers().get(header::ETAG).cloned(),
+            last_modified: resp.headers().get(header::LAST_MODIFIED).cloned(),
+        };
+        let body = resp.text().await?;
+        let data = parse_json::<T>(&body).inspect_err(|err| warn!(url = %key, %err, "bad response body"))?;
+        // Only remember validators once the body has parsed, or a bad response would stick
+        self.validators.lock().unwrap().insert(key, fresh);
         Ok(data)
     }
 }
 
+impl ScoreboardSource for DataClient {
+    async fn fetch_scoreboard(&self, league: &str, query: &ScoreboardQuery) -> Result<ScoreboardResponse> {
+        let path = format!("site/v2/sports/football/{}/scoreboard", league);
+        self.get_json(&path, &query.params()).await
+    }
+
+    async fn fetch_summary(&self, league: &str, event_id: &str) -> Result<Summary> {
+        let path = format!("site/v2/sports/football/{}/summary", league);
+        self.get_json(&path, &[("event", event_id.to_string())]).await
+    }
+
+    async fn fetch_news(&self, league: &str) -> Result<News> {
+        let path = format!("site/v2/sports/football/{}/news", league);
+        self.get_json(&path, &[]).await
+    }
+
+    async fn fetch_standings(&self, league: &str) -> Result<Standings> {
+        // Standings live on the v2 API rather than site/v2; level=3 splits conferences into divisions
+        let path = format!("v2/sports/football/{}/standings", league);
+        self.get_json(&path, &[("level", "3".to_string())]).await
+    }
+
+    async fn fetch_rankings(&self, league: &str) -> Result<Rankings> {
+        let path = format!("site/v2/sports/football/{}/rankings", league);
+        self.get_json(&path, &[]).await
+    }
+
+    async fn fetch_schedule(&self, league: &str, team_id: &str, season: i32, season_type: SeasonType) -> Result<Schedule> {
+        let path = format!("site/v2/sports/football/{}/teams/{}/schedule", league, team_id);
+        self.get_json(&path, &[("season", season.to_string()), ("seasontype", season_type.number().to_string())]).await
+    }
+}
+
+/// Serves canned data; anything left as `None` fails like an unreachable API.
+#[cfg(test)]
+#[derive(Default)]
+pub struct MockSource {
+    pub scoreboard: Option<ScoreboardResponse>,
+    pub summary: Option<Summary>,
+    pub news: Option<News>,
+    pub standings: Option<Standings>,
+    pub rankings: Option<Rankings>,
+    pub schedules: HashMap<(i32, SeasonType), Schedule>,
+    // How many schedules have been asked for, found or not
+    pub schedule_fetches: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(test)]
+fn canned<T: Clone>(data: &Option<T>) -> Result<T> {
+    data.clone().ok_or_else(|| anyhow::anyhow!("mock source has no data"))
+}
+
+#[cfg(test)]
+impl ScoreboardSource for MockSource {
+    async fn fetch_scoreboard(&self, _league: &str, _query: &ScoreboardQuery) -> Result<ScoreboardResponse> {
+        canned(&self.scoreboard)
+    }
+
+    async fn fetch_summary(&self, _league: &str, _event_id: &str) -> Result<Summary> {
+        canned(&self.summary)
+    }
+
+    async fn fetch_news(&self, _league: &str) -> Result<News> {
+        canned(&self.news)
+    }
+
+    async fn fetch_standings(&self, _league: &str) -> Result<Standings> {
+        canned(&self.standings)
+    }
+
+    async fn fetch_rankings(&self, _league: &str) -> Result<Rankings> {
+        canned(&self.rankings)
+    }
+
+    async fn fetch_schedule(&self, _league: &str, _team_id: &str, season: i32, season_type: SeasonType) -> Result<Schedule> {
+        self.schedule_fetches.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        canned(&self.schedules.get(&(season, season_type)).cloned())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::{Competitor, Coverage, CuratedRank, GameState, GeoBroadcast, Odds};
     use std::fs;
 
+    // The regular season sample: a live Rams game and the final Cowboys-Commanders game
+    fn sample() -> ScoreboardResponse {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        serde_json::from_str(&content).expect("Failed to deserialize")
+    }
+
     #[test]
     fn test_deserialize_sample() {
-        // Read the sample file created earlier
-        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
-        let _data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        let data = sample();
+        assert_eq!(data.week.map(|w| w.number), Some(17));
+        let season = data.events[0].season.as_ref().expect("Missing event season");
+        assert_eq!((season.type_field, season.slug.as_deref()), (2, Some("regular-season")));
+        let situation = data.events[0].competitions[0].situation.as_ref().expect("Missing situation");
+        assert_eq!(situation.is_red_zone, Some(false));
+
+        // Final games carry one linescore per quarter
+        let wsh = &data.events[1].competitions[0].competitors[0];
+        let points: f64 = wsh.linescores.as_ref().expect("Missing linescores").iter().map(|l| l.value).sum();
+        assert_eq!(points.to_string(), wsh.score.clone().unwrap());
+
+        let leaders = data.events[1].competitions[0].leaders.as_ref().expect("Missing leaders");
+        assert_eq!(leaders.len(), 3);
+        assert_eq!(leaders[0].leaders[0].display_value, "19/37, 307 YDS, 2 TD");
+    }
+
+    #[test]
+    fn test_game_state() {
+        let data = sample();
+        assert_eq!(data.events[0].status.type_field.state, GameState::In);
+        assert_eq!(data.events[1].status.type_field.state, GameState::Post);
+        let delayed: GameState = serde_json::from_str(r#""delayed""#).unwrap();
+        assert_eq!(delayed, GameState::Other("delayed".to_string()));
+        assert_eq!(serde_json::to_string(&GameState::In).unwrap(), r#""in""#);
+    }
+
+    #[test]
+    fn test_fuzzy_matches() {
+        let game = &sample().events[0];
+        assert!(game.fuzzy_matches("lar"));
+        assert!(game.fuzzy_matches("L Rams"));
+        assert!(game.fuzzy_matches("falc"));
+        assert!(!game.fuzzy_matches("chiefs"));
+    }
+
+    #[test]
+    fn test_is_playoff() {
+        let data = sample();
+        assert!(data.events[0].kickoff().is_some());
+
+        // Bowls are told apart from CFP games by their notes
+        let mut bowl = data.events[0].clone();
+        bowl.competitions[0].notes = vec![crate::model::Note { type_field: None, headline: "Rose Bowl".to_string() }];
+        assert!(!bowl.is_playoff());
+        bowl.competitions[0].notes[0].headline = "College Football Playoff Quarterfinal at the Rose Bowl".to_string();
+        assert!(bowl.is_playoff());
+    }
+
+    #[test]
+    fn test_records() {
+        let data = sample();
+        let lar = &data.events[0].competitions[0].competitors[1];
+        assert_eq!(lar.record(), Some("11-4"));
+        assert_eq!(lar.venue_record(true), Some("6-1"));
+        assert_eq!(lar.venue_record(false), Some("5-3"));
+    }
+
+    #[test]
+    fn test_weather() {
+        let data = sample();
+        let weather = data.events[0].weather.as_ref().expect("Missing weather");
+        assert_eq!(weather.summary().as_deref(), Some("37°F, Clear"));
+        assert!(data.events[1].weather.is_none());
+    }
+
+    #[test]
+    fn test_subtitle() {
+        let data = sample();
+        assert_eq!(data.events[0].subtitle(), None);
+        assert_eq!(
+            data.events[1].subtitle(),
+            Some("Dak Prescott throws for 307 yards and 2 TDs to lead Cowboys past skidding Commanders 30-23")
+        );
+    }
+
+    #[test]
+    fn test_geo_broadcasts() {
+        let data = sample();
+        // The Christmas games were on Netflix alone
+        let geo = &data.events[1].competitions[0].geo_broadcasts;
+        assert_eq!(geo.len(), 1);
+        assert_eq!((geo[0].name(), geo[0].coverage()), (Some("Netflix"), Some(Coverage::Streaming)));
+        let espn = &data.events[0].competitions[0].geo_broadcasts[0];
+        assert_eq!((espn.name(), espn.coverage()), (Some("ESPN"), Some(Coverage::National)));
+        let local: GeoBroadcast = serde_json::from_str(r#"{"type": {"shortName": "TV"}, "market": {"type": "Home"}, "media": {"shortName": "KCBS"}}"#).unwrap();
+        assert_eq!(local.coverage(), Some(Coverage::Local));
+        let radio: GeoBroadcast = serde_json::from_str(r#"{"type": {"shortName": "Radio"}}"#).unwrap();
+        assert_eq!(radio.coverage(), None);
+    }
+
+    #[test]
+    fn test_bad_response_path() {
+        let err = parse_json::<ScoreboardResponse>(r#"{"events": [], "week": {"number": "one"}}"#).unwrap_err();
+        assert_eq!(err.path, "week.number");
+        let body = "{\n  \"boxscore\": {\"teams\": [\n    {\"team\": {\"id\": \"1\"}},\n    {\"statistics\": 5}\n  ]}\n}";
+        let err = parse_json::<Summary>(body).unwrap_err();
+        assert_eq!(err.path, "boxscore.teams[1].statistics");
+        assert!(err.to_string().starts_with("unexpected data at boxscore.teams[1].statistics: invalid type"));
+        assert_eq!(parse_json::<ScoreboardResponse>("5").unwrap_err().path, ".");
+    }
+
+    #[test]
+    fn test_deserialize_sparse_pregame() {
+        let content = fs::read_to_string("espn_pregame_sparse.json").expect("Failed to read pregame file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        // The game with nonsense for competitions is dropped; the rest still load
+        let ids: Vec<&str> = data.events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["401772510", "401772512"]);
+
+        let game = &data.events[0];
+        assert_eq!(game.status.type_field.state, GameState::Pre);
+        assert_eq!(game.status.type_field.description, "");
+        let comp = &game.competitions[0];
+        assert!(comp.situation.is_none() && comp.broadcasts.is_none() && comp.odds.is_none());
+        assert!(comp.competitors[0].score.is_none());
+        assert_eq!(comp.competitors[0].record(), None);
+        assert_eq!(comp.competitors[1].team.short_display_name, "");
+
+        // No status at all reads as a game that hasn't started
+        assert_eq!(data.events[1].status.type_field.state, GameState::Pre);
+        assert!(data.events[1].kickoff().is_none());
+    }
+
+    #[test]
+    fn test_deserialize_postseason() {
+        let content = fs::read_to_string("espn_postseason.json").expect("Failed to read postseason file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        assert_eq!(data.season.as_ref().map(|s| s.type_field), Some(3));
+        let game = &data.events[0];
+        assert_eq!(game.note(), Some("NFC Wild Card Playoffs"));
+        assert_eq!(game.subtitle(), game.note());
+        assert_eq!((game.status.period, game.status.type_field.short_detail.as_str()), (5, "Final/OT"));
+        let away = &game.competitions[0].competitors[1];
+        assert_eq!(away.linescores.as_ref().map(|l| l.len()), Some(5));
+        assert!(away.team.color.is_none() && away.records.is_empty());
+        assert_eq!(away.linescores.as_ref().unwrap()[0].period, None);
+    }
+
+    #[test]
+    fn test_deserialize_news() {
+        let content = fs::read_to_string("espn_news.json").expect("Failed to read news file");
+        let data: News = serde_json::from_str(&content).expect("Failed to deserialize");
+        assert_eq!(data.articles.len(), 3);
+        assert_eq!(data.articles[0].published.as_deref(), Some("2025-12-30T01:45:12Z"));
+    }
+
+    #[test]
+    fn test_deserialize_odds() {
+        let content = r#"{
+            "provider": {"id": "58", "name": "ESPN BET", "priority": 1},
+            "details": "KC -3.5",
+            "overUnder": 47.5,
+            "spread": -3.5,
+            "awayTeamOdds": {"favorite": false, "underdog": true, "moneyLine": 150},
+            "homeTeamOdds": {"favorite": true, "underdog": false, "moneyLine": -175}
+        }"#;
+        let odds: Odds = serde_json::from_str(content).expect("Failed to deserialize");
+        assert_eq!(odds.details.as_deref(), Some("KC -3.5"));
+        assert_eq!(odds.over_under, Some(47.5));
+        assert!(odds.home_team_odds.is_some_and(|o| o.favorite));
+    }
+
+    #[test]
+    fn test_scoreboard_query_params() {
+        assert!(ScoreboardQuery::default().params().is_empty());
+
+        let query = ScoreboardQuery { week: Some(12), dates: Some("20251130".to_string()), ..Default::default() };
+        assert_eq!(query.params(), vec![("week", "12".to_string()), ("dates", "20251130".to_string())]);
+
+        let query = ScoreboardQuery { groups: Some("8".to_string()), ..Default::default() };
+        assert_eq!(query.params(), vec![("groups", "8".to_string())]);
+        assert_eq!(group_name("8"), "SEC");
+
+        let query = ScoreboardQuery { week: Some(1), season_type: Some(SeasonType::Postseason), ..Default::default() };
+        assert_eq!(query.params(), vec![("week", "1".to_string()), ("seasontype", "3".to_string())]);
+        assert_eq!(SeasonType::cycle(Some(SeasonType::Postseason)), None);
+
+        assert_eq!(parse_date("20251225"), Ok("20251225".to_string()));
+        assert_eq!(parse_date("2025-12-25"), Ok("20251225".to_string()));
+        assert!(parse_date("20251332").is_err());
+        assert!(parse_date("christmas").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_summary() {
+        let content = fs::read_to_string("espn_summary.json").expect("Failed to read summary file");
+        let data: Summary = serde_json::from_str(&content).expect("Failed to deserialize");
+        let drives = data.drives();
+        // The in-progress drive is repeated in `previous` and must only appear once
+        assert_eq!(drives.len(), 3);
+        assert_eq!(drives[1].plays.iter().filter(|p| p.scoring_play).count(), 2);
+
+        // No `scoringPlays` in this payload, so they come from the drives
+        let scores = data.scoring_plays();
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[1].team.as_ref().and_then(|t| t.abbreviation.as_deref()), Some("ATL"));
+        assert_eq!((scores[1].away_score, scores[1].home_score), (Some(0), Some(7)));
+
+        let boxscore = data.boxscore.expect("Missing boxscore");
+        assert_eq!(boxscore.teams.len(), 2);
+        assert_eq!(boxscore.teams[0].stat("totalYards"), Some("41"));
+        assert_eq!(boxscore.teams[0].conversions("thirdDownEff"), Some((1, 3)));
+        assert_eq!(boxscore.teams[0].conversions("totalYards"), None);
+        assert_eq!(boxscore.teams[0].possession_secs(), Some(6 * 60 + 12));
+        assert_eq!(boxscore.teams[0].conversions("totalPenaltiesYards"), Some((1, 5)));
+
+        let flagged = crate::model::LastPlay {
+            text: "(12:04) PENALTY on ATL-D.Bates, False Start, 5 yards, enforced at ATL 25 - No Play.".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(flagged.penalty(), Some("PENALTY on ATL-D.Bates, False Start, 5 yards"));
+        assert_eq!(crate::model::LastPlay::default().penalty(), None);
+        assert_eq!(boxscore.players[1].statistics[0].athletes[0].athlete.display_name, "Michael Penix Jr.");
+
+        let (away, home) = data.winprobability.last().expect("Missing win probability").split();
+        assert!((away - 0.6199).abs() < 1e-9 && (home - 0.3801).abs() < 1e-9);
+
+        // Recaps only show up once a game is over
+        assert!(data.article.is_none());
+        let recap: Summary = serde_json::from_str(r#"{"article": {"headline": "Falcons hold off Rams", "description": "Bijan Robinson ran for two scores."}}"#).unwrap();
+        assert_eq!(recap.article.map(|a| a.headline), Some("Falcons hold off Rams".to_string()));
+
+        let venue = data.game_info.and_then(|g| g.venue).expect("Missing venue");
+        assert_eq!(venue.full_name, "Mercedes-Benz Stadium");
+
+        let preview: Summary = serde_json::from_str(
+            r#"{"injuries": [{"team": {"abbreviation": "ATL"}, "injuries": [{"status": "Out", "athlete": {"displayName": "Drake London", "position": {"abbreviation": "WR"}}}]}],
+                "seasonseries": [{"summary": "ATL leads series 1-0", "events": [{"date": "2025-10-12T17:00Z", "competitors": [{"team": {"abbreviation": "ATL"}, "score": "24", "winner": true}]}]}]}"#,
+        )
+        .unwrap();
+        let injury = &preview.injuries[0].injuries[0];
+        assert_eq!(injury.athlete.as_ref().and_then(|a| a.position.as_ref()).and_then(|p| p.abbreviation.as_deref()), Some("WR"));
+        assert_eq!(preview.season_series[0].events[0].competitors[0].winner, Some(true));
+    }
+
+    #[test]
+    fn test_with_proxy() {
+        assert!(DataClient::new().with_proxy("http://127.0.0.1:8080").is_ok());
+        assert!(DataClient::new().with_proxy("not a url").is_err());
+    }
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(1, 15), Duration::from_secs(2));
+        assert_eq!(backoff(3, 15), Duration::from_secs(8));
+        assert_eq!(backoff(20, 15), Duration::from_secs(60));
+        assert_eq!(backoff(20, 300), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn test_deserialize_standings() {
+        let content = fs::read_to_string("espn_standings.json").expect("Failed to read standings file");
+        let data: Standings = serde_json::from_str(&content).expect("Failed to deserialize");
+        let groups = data.groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "AFC East");
+
+        let leader = &groups[0].standings.as_ref().unwrap().entries[0];
+        assert_eq!(leader.stat("streak"), Some("W3"));
+        assert_eq!(leader.stat("vsdiv"), Some("5-0"));
+
+        let seeds = data.playoff_seeds();
+        assert_eq!(seeds["NE"], ("AFC".to_string(), 2));
+        assert_eq!(seeds["CAR"], ("NFC".to_string(), 4));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_bracket() {
+        let scoreboard = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let source = MockSource {
+            scoreboard: Some(serde_json::from_str(&scoreboard).expect("Failed to deserialize")),
+            ..Default::default()
+        };
+        // No standings: still a bracket, just without seeds
+        let bracket = fetch_bracket(&source, "nfl", None).await.expect("Bracket failed");
+        let names: Vec<_> = bracket.rounds.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["Wild Card", "Divisional", "Conference", "Super Bowl"]);
+        assert!(bracket.seeds.is_empty());
+
+        assert!(fetch_bracket(&MockSource::default(), "nfl", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_head_to_head() {
+        // A played game between teams 1 and 14, plus one of team 1's against someone else
+        let schedule = |ids: &[&str]| -> Schedule {
+            let mut events: Vec<String> = ids
+                .iter()
+                .map(|id| {
+                    format!(
+                        r#"{{"id": "{}", "date": "20{}T17:00Z", "competitions": [{{"competitors": [
+                            {{"team": {{"id": "1"}}, "score": {{"displayValue": "20"}}, "winner": false, "homeAway": "home"}},
+                            {{"team": {{"id": "14"}}, "score": {{"displayValue": "27"}}, "winner": true, "homeAway": "away"}}]}}]}}"#,
+                        id,
+                        &id[1..]
+                    )
+                })
+                .collect();
+            events.push(
+                r#"{"id": "other", "date": "2025-09-14T17:00Z", "competitions": [{"competitors": [
+                    {"team": {"id": "1"}, "score": {"displayValue": "13"}, "winner": true, "homeAway": "home"},
+                    {"team": {"id": "3"}, "score": {"displayValue": "10"}, "winner": false, "homeAway": "away"}]}]}"#
+                    .to_string(),
+            );
+            serde_json::from_str(&format!(r#"{{"events": [{}]}}"#, events.join(","))).unwrap()
+        };
+        // Ids are "g" plus the date, so they sort the way the games should
+        let source = MockSource {
+            schedules: HashMap::from([
+                ((2025, SeasonType::Postseason), schedule(&["g26-01-10"])),
+                // The playoff game shows up in the regular season list too
+                ((2025, SeasonType::Regular), schedule(&["g25-09-07", "g26-01-10", "g25-12-28"])),
+                ((2024, SeasonType::Postseason), schedule(&[])),
+                ((2024, SeasonType::Regular), schedule(&["g24-10-06"])),
+                ((2023, SeasonType::Postseason), schedule(&[])),
+                ((2023, SeasonType::Regular), schedule(&["g23-09-10", "g23-11-19"])),
+            ]),
+            ..Default::default()
+        };
+
+        let meetings = fetch_head_to_head(&source, "nfl", "1", "14", 2025).await.expect("Fetch failed");
+        let ids: Vec<_> = meetings.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["g26-01-10", "g25-12-28", "g25-09-07", "g24-10-06", "g23-11-19"]);
+        assert_eq!(meetings[0].competitors[1].score.as_deref(), Some("27"));
+        // Five found by 2023, so 2022 and earlier are never asked for
+        assert_eq!(source.schedule_fetches.load(std::sync::atomic::Ordering::Relaxed), 6);
+
+        assert_eq!(matchup_key("14", "1"), matchup_key("1", "14"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_head_to_head_missing_season() {
+        let schedule = |id: &str| -> Schedule {
+            serde_json::from_str(&format!(
+                r#"{{"events": [{{"id": "{}", "date": "2024-10-06T17:00Z", "competitions": [{{"competitors": [
+                    {{"team": {{"id": "1"}}, "score": {{"displayValue": "20"}}, "winner": false, "homeAway": "home"}},
+                    {{"team": {{"id": "14"}}, "score": {{"displayValue": "27"}}, "winner": true, "homeAway": "away"}}]}}]}}]}}"#,
+                id
+            ))
+            .unwrap()
+        };
+        // Only 2025 and 2023 regular seasons exist; every other request fails
+        let source = MockSource {
+            schedules: HashMap::from([((2025, SeasonType::Regular), schedule("a")), ((2023, SeasonType::Regular), schedule("b"))]),
+            ..Default::default()
+        };
+        let meetings = fetch_head_to_head(&source, "nfl", "1", "14", 2025).await.expect("Fetch failed");
+        let ids: Vec<_> = meetings.iter().filter_map(|m| m.id.as_deref()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+
+        // Nothing fetched at all is still an error
+        assert!(fetch_head_to_head(&MockSource::default(), "nfl", "1", "14", 2025).await.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rankings() {
+        let content = fs::read_to_string("espn_rankings.json").expect("Failed to read rankings file");
+        let data: Rankings = serde_json::from_str(&content).expect("Failed to deserialize");
+        let polls = data.main_polls();
+        assert_eq!(polls.len(), 2);
+        assert_eq!(polls[0].name, "AP Top 25");
+
+        let top = &polls[0].ranks[0];
+        assert_eq!(top.current, 1);
+        assert_eq!(top.team.abbreviation.as_deref(), Some("UGA"));
+        assert_eq!(top.first_place_votes, Some(40));
+
+        // Scoreboard competitors carry the rank too, with 99 meaning unranked
+        let ranked: Competitor = serde_json::from_str(r#"{"team": {"abbreviation": "UGA", "displayName": "Georgia Bulldogs", "shortDisplayName": "Georgia"}, "homeAway": "home", "curatedRank": {"current": 3}}"#).unwrap();
+        assert_eq!(ranked.label(), "#3 UGA");
+        let unranked = Competitor { curated_rank: Some(CuratedRank { current: Some(99) }), ..ranked };
+        assert_eq!(unranked.label(), "UGA");
+    }
+
+    mod http {
+        use super::*;
+        use wiremock::matchers::{header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        const SCOREBOARD: &str = "/site/v2/sports/football/nfl/scoreboard";
+
+        async fn serve(status: u16, body: &str) -> MockServer {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path(SCOREBOARD))
+                .respond_with(ResponseTemplate::new(status).set_body_string(body))
+                .mount(&server)
+                .await;
+            server
+        }
+
+        #[tokio::test]
+        async fn test_fetch_scoreboard() {
+            let server = MockServer::start().await;
+            let body = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+            Mock::given(method("GET"))
+                .and(path(SCOREBOARD))
+                .and(query_param("week", "17"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(body))
+                .mount(&server)
+                .await;
+
+            let client = DataClient::with_base_url(server.uri());
+            let query = ScoreboardQuery { week: Some(17), ..Default::default() };
+            let data = client.fetch_scoreboard("nfl", &query).await.expect("Fetch failed");
+            assert_eq!(data.events.len(), 16);
+        }
+
+        #[tokio::test]
+        async fn test_empty_slate() {
+            let server = serve(200, r#"{"events": []}"#).await;
+            let data = DataClient::with_base_url(server.uri())
+                .fetch_scoreboard("nfl", &ScoreboardQuery::default())
+                .await
+                .expect("An empty slate is still a valid response");
+            assert!(data.events.is_empty());
+            assert!(data.week.is_none());
+        }
+
+        #[tokio::test]
+        async fn test_malformed_json() {
+            let server = serve(200, r#"{"events": [{"id": "#).await;
+            let result = DataClient::with_base_url(server.uri()).fetch_scoreboard("nfl", &ScoreboardQuery::default()).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_server_error() {
+            let server = serve(503, "Service Unavailable").await;
+            let result = DataClient::with_base_url(server.uri()).fetch_scoreboard("nfl", &ScoreboardQuery::default()).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_unreachable() {
+            // Grab a free port, then close it so nothing is listening there
+            let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+            let uri = format!("http://127.0.0.1:{}", port);
+            let result = DataClient::with_base_url(uri).fetch_scoreboard("nfl", &ScoreboardQuery::default()).await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_not_modified() {
+            let server = MockServer::start().await;
+            let body = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+            Mock::given(method("GET"))
+                .and(path(SCOREBOARD))
+                .and(header("If-None-Match", "\"v1\""))
+                .respond_with(ResponseTemplate::new(304))
+                .with_priority(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path(SCOREBOARD))
+                .respond_with(ResponseTemplate::new(200).insert_header("ETag", "\"v1\"").set_body_string(body))
+                .mount(&server)
+                .await;
+
+            let client = DataClient::with_base_url(server.uri());
+            let query = ScoreboardQuery::default();
+            assert!(client.fetch_scoreboard("nfl", &query).await.is_ok());
+            let err = client.fetch_scoreboard("nfl", &query).await.expect_err("Second fetch should be a 304");
+            assert!(err.is::<NotModified>());
+
+            // Validators are per URL, so another week is fetched in full
+            let week = ScoreboardQuery { week: Some(3), ..Default::default() };
+            assert!(client.fetch_scoreboard("nfl", &week).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn test_fetch_standings_path() {
+            let server = MockServer::start().await;
+            let body = fs::read_to_string("espn_standings.json").expect("Failed to read standings file");
+            Mock::given(method("GET"))
+                .and(path("/v2/sports/football/nfl/standings"))
+                .and(query_param("level", "3"))
+                .respond_with(ResponseTemplate::new(200).set_body_string(body))
+                .mount(&server)
+                .await;
+
+            let data = DataClient::with_base_url(server.uri()).fetch_standings("nfl").await.expect("Fetch failed");
+            assert_eq!(data.groups().len(), 2);
+        }
     }
 }
@@ -0,0 +1,301 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::mpsc::Sender;
+
+use crate::model::{
+    Competition, Competitor, Event, GameState, Linescore, ScoreboardResponse, Situation, Status, StatusType, Team, Week,
+};
+use crate::Update;
+
+// How often the demo scoreboard moves on; one play per tick
+const TICK: Duration = Duration::from_secs(2);
+// Game seconds each play takes off the clock
+const PLAY_SECS: i32 = 40;
+const QUARTER_SECS: i32 = 900;
+
+const TEAMS: &[(&str, &str, &str)] = &[
+    ("KC", "Kansas City Chiefs", "e31837"),
+    ("BUF", "Buffalo Bills", "00338d"),
+    ("PHI", "Philadelphia Eagles", "004c54"),
+    ("DAL", "Dallas Cowboys", "002a5c"),
+    ("SF", "San Francisco 49ers", "aa0000"),
+    ("SEA", "Seattle Seahawks", "002a5c"),
+    ("DET", "Detroit Lions", "0076b6"),
+    ("GB", "Green Bay Packers", "204e32"),
+    ("BAL", "Baltimore Ravens", "29126f"),
+    ("PIT", "Pittsburgh Steelers", "000000"),
+    ("MIA", "Miami Dolphins", "008e97"),
+    ("NYJ", "New York Jets", "125740"),
+    ("LAR", "Los Angeles Rams", "003594"),
+    ("ATL", "Atlanta Falcons", "a71930"),
+    ("DEN", "Denver Broncos", "0a2343"),
+    ("LV", "Las Vegas Raiders", "000000"),
+];
+
+// Kickoff tick for each matchup; negative ones are already under way at startup
+const KICKOFFS: &[i32] = &[-60, -40, -20, -5, 0, 15, 30, 45];
+
+#[derive(Debug, Clone)]
+struct DemoGame {
+    away: usize,
+    home: usize,
+    kickoff: i32,
+    away_lines: Vec<f64>,
+    home_lines: Vec<f64>,
+    period: i32,
+    clock: i32,
+    home_has_ball: bool,
+    to_go: i32,
+    down: i32,
+    distance: i32,
+}
+
+impl DemoGame {
+    fn score(lines: &[f64]) -> String {
+        (lines.iter().sum::<f64>() as i64).to_string()
+    }
+
+    fn add_points(&mut self, points: f64) {
+        let lines = if self.home_has_ball { &mut self.home_lines } else { &mut self.away_lines };
+        if let Some(quarter) = lines.last_mut() {
+            *quarter += points;
+        }
+    }
+
+    fn change_possession(&mut self, to_go: i32) {
+        self.home_has_ball = !self.home_has_ball;
+        self.to_go = to_go.clamp(1, 99);
+        self.down = 1;
+        self.distance = 10.min(self.to_go);
+    }
+
+    fn is_over(&self) -> bool {
+        self.period > 4
+    }
+}
+
+/// Made-up games that kick off and play out over a few minutes, for working on
+/// the UI without network access.
+pub struct Demo {
+    games: Vec<DemoGame>,
+    tick: i32,
+    rng: u64,
+}
+
+impl Demo {
+    pub fn new(seed: u64) -> Self {
+        let games = KICKOFFS
+            .iter()
+            .enumerate()
+            .map(|(i, &kickoff)| DemoGame {
+                away: i * 2,
+                home: i * 2 + 1,
+                kickoff,
+                away_lines: vec![0.0],
+                home_lines: vec![0.0],
+                period: 1,
+                clock: QUARTER_SECS,
+                home_has_ball: false,
+                to_go: 75,
+                down: 1,
+                distance: 10,
+            })
+            .collect();
+        let mut demo = Self { games, tick: 0, rng: seed | 1 };
+        // Fast-forward games that started before launch
+        let earliest = KICKOFFS.iter().copied().min().unwrap_or(0);
+        for tick in earliest..0 {
+            demo.tick = tick;
+            demo.advance();
+        }
+        demo.tick = 0;
+        demo
+    }
+
+    // xorshift64; plenty random for fake football
+    fn roll(&mut self, n: u64) -> i32 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        (self.rng % n) as i32
+    }
+
+    fn advance(&mut self) {
+        for i in 0..self.games.len() {
+            if self.tick < self.games[i].kickoff || self.games[i].is_over() {
+                continue;
+            }
+            let gain = self.roll(15) - 3;
+            let kick_good = self.roll(4) != 0;
+            let game = &mut self.games[i];
+
+            game.to_go -= gain;
+            if game.to_go <= 0 {
+                game.add_points(7.0);
+                game.change_possession(75);
+            } else if gain >= game.distance {
+                game.down = 1;
+                game.distance = 10.min(game.to_go);
+            } else if game.down == 4 {
+                if game.to_go <= 35 {
+                    if kick_good {
+                        game.add_points(3.0);
+                    }
+                    game.change_possession(75);
+                } else {
+                    // Punt
+                    game.change_possession(100 - (game.to_go - 40));
+                }
+            } else {
+                game.down += 1;
+                game.distance -= gain;
+            }
+
+            game.clock -= PLAY_SECS;
+            if game.clock <= 0 {
+                game.period += 1;
+                game.clock = QUARTER_SECS;
+                if !game.is_over() {
+                    game.away_lines.push(0.0);
+                    game.home_lines.push(0.0);
+                }
+            }
+        }
+    }
+
+    /// Plays one more snap in every game in progress and returns the new scoreboard.
+    pub fn step(&mut self) -> ScoreboardResponse {
+        self.tick += 1;
+        self.advance();
+        self.scoreboard()
+    }
+
+    pub fn scoreboard(&self) -> ScoreboardResponse {
+        let events = self.games.iter().enumerate().map(|(i, game)| self.event(i, game)).collect();
+        ScoreboardResponse {
+            events,
+            week: Some(Week { number: 1 }),
+            season: None,
+        }
+    }
+
+    fn event(&self, index: usize, game: &DemoGame) -> Event {
+        let (state, short_detail) = if self.tick < game.kickoff {
+            let secs = (game.kickoff - self.tick) as u64 * TICK.as_secs();
+            (GameState::Pre, format!("Kickoff in {}:{:02}", secs / 60, secs % 60))
+        } else if game.is_over() {
+            (GameState::Post, "Final".to_string())
+        } else {
+            let ordinal = ["1st", "2nd", "3rd", "4th"][(game.period - 1) as usize];
+            (GameState::In, format!("{}:{:02} - {}", game.clock / 60, game.clock % 60, ordinal))
+        };
+        let status = Status {
+            period: game.period.min(4),
+            display_clock: format!("{}:{:02}", game.clock / 60, game.clock % 60),
+            clock: Some(game.clock as f64),
+            type_field: StatusType {
+                state: state.clone(),
+                short_detail: short_detail.clone(),
+                description: short_detail.clone(),
+                detail: short_detail,
+            },
+        };
+
+        let competitor = |team: usize, home_away: &str, lines: &[f64]| {
+            let (abbreviation, name, color) = TEAMS[team];
+            Competitor {
+                team: Team {
+                    id: Some(team.to_string()),
+                    abbreviation: abbreviation.to_string(),
+                    display_name: name.to_string(),
+                    short_display_name: name.rsplit(' ').next().unwrap_or(name).to_string(),
+                    color: Some(color.to_string()),
+                    ..Default::default()
+                },
+                score: (state != GameState::Pre).then(|| DemoGame::score(lines)),
+                home_away: home_away.to_string(),
+                linescores: (state != GameState::Pre).then(|| {
+                    lines
+                        .iter()
+                        .enumerate()
+                        .map(|(q, &value)| Linescore { value, period: Some(q as i32 + 1) })
+                        .collect()
+                }),
+                ..Default::default()
+            }
+        };
+
+        let situation = (state == GameState::In).then(|| {
+            let offense = if game.home_has_ball { game.home } else { game.away };
+            let ordinal = ["1st", "2nd", "3rd", "4th"][(game.down - 1) as usize];
+            Situation {
+                down: Some(game.down),
+                distance: Some(game.distance),
+                yard_line: Some(game.to_go),
+                short_down_distance_text: Some(format!("{} & {}", ordinal, game.distance)),
+                possession: Some(offense.to_string()),
+                is_red_zone: Some(game.to_go <= 20),
+                ..Default::default()
+            }
+        });
+
+        let (away, home) = (TEAMS[game.away].0, TEAMS[game.home].0);
+        Event {
+            id: format!("demo-{}", index),
+            date: Some(format!("2025-09-07T{:02}:00Z", 17 + index / 3)),
+            short_name: format!("{} @ {}", away, home),
+            competitions: vec![Competition {
+                competitors: vec![
+                    competitor(game.home, "home", &game.home_lines),
+                    competitor(game.away, "away", &game.away_lines),
+                ],
+                status: status.clone(),
+                situation,
+                ..Default::default()
+            }],
+            status,
+            ..Default::default()
+        }
+    }
+}
+
+/// Stands in for the fetch task under `--demo`.
+pub async fn run(tx: Sender<Update>) {
+    let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(1);
+    let mut demo = Demo::new(seed);
+    if tx.send(Update::Scoreboard(demo.scoreboard())).await.is_err() {
+        return;
+    }
+    loop {
+        tokio::time::sleep(TICK).await;
+        if tx.send(Update::Scoreboard(demo.step())).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_games_play_out() {
+        let mut demo = Demo::new(42);
+        let states = |data: &ScoreboardResponse| -> Vec<GameState> {
+            data.events.iter().map(|e| e.status.type_field.state.clone()).collect()
+        };
+        let start = states(&demo.scoreboard());
+        assert!(start.contains(&GameState::In) && start.contains(&GameState::Pre));
+
+        let mut last = demo.scoreboard();
+        for _ in 0..300 {
+            last = demo.step();
+        }
+        assert!(states(&last).iter().all(|s| *s == GameState::Post));
+        // Linescores always add up to the score
+        for competitor in last.events.iter().flat_map(|e| &e.competitions[0].competitors) {
+            let total: f64 = competitor.linescores.as_ref().unwrap().iter().map(|l| l.value).sum();
+            assert_eq!(total.to_string(), competitor.score.clone().unwrap());
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::{collections::BTreeSet, fs, path::PathBuf};
+
+use anyhow::Result;
+
+/// Favorite teams by abbreviation: fixed ones from config plus ones starred in the app,
+/// which are persisted so they survive restarts.
+#[derive(Debug, Default)]
+pub struct Favorites {
+    configured: BTreeSet<String>,
+    starred: BTreeSet<String>,
+    path: Option<PathBuf>,
+}
+
+impl Favorites {
+    /// `~/.local/share/nfl-tui/favorites.json` (or the platform equivalent).
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("nfl-tui").join("favorites.json"))
+    }
+
+    pub fn load(configured: &[String], path: Option<PathBuf>) -> Self {
+        // A missing or corrupt store just means nothing has been starred yet
+        let starred = path
+            .as_ref()
+            .and_then(|p| fs::read_to_string(p).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            configured: configured.iter().map(|t| t.to_uppercase()).collect(),
+            starred,
+            path,
+        }
+    }
+
+    pub fn contains(&self, abbr: &str) -> bool {
+        self.configured.contains(abbr) || self.starred.contains(abbr)
+    }
+
+    /// Every favorite, configured or starred.
+    pub fn teams(&self) -> BTreeSet<String> {
+        self.configured.union(&self.starred).cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.configured.is_empty() && self.starred.is_empty()
+    }
+
+    pub fn star(&mut self, abbr: &str) {
+        self.starred.insert(abbr.to_string());
+    }
+
+    pub fn unstar(&mut self, abbr: &str) {
+        self.starred.remove(abbr);
+    }
+
+    /// Cycles a matchup through away starred -> home starred -> neither.
+    /// Teams fixed in config stay favorites regardless.
+    pub fn cycle(&mut self, away: &str, home: &str) -> Result<()> {
+        // Goes by the stars, since a configured team is always a favorite; a configured
+        // away team skips straight to starring the home team
+        if self.starred.contains(home) {
+            self.unstar(home);
+            self.unstar(away);
+        } else if self.starred.contains(away) || self.configured.contains(away) {
+            self.unstar(away);
+            self.star(home);
+        } else {
+            self.star(away);
+        }
+        self.save()
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&self.starred)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle() {
+        let mut favorites = Favorites::load(&[], None);
+        favorites.cycle("LAR", "ATL").unwrap();
+        assert!(favorites.contains("LAR") && !favorites.contains("ATL"));
+        favorites.cycle("LAR", "ATL").unwrap();
+        assert!(!favorites.contains("LAR") && favorites.contains("ATL"));
+        favorites.cycle("LAR", "ATL").unwrap();
+        assert!(favorites.is_empty());
+    }
+
+    #[test]
+    fn test_configured_teams_stay() {
+        let mut favorites = Favorites::load(&["kc".to_string()], None);
+        assert!(favorites.contains("KC"));
+        favorites.cycle("DEN", "KC").unwrap();
+        assert!(favorites.contains("KC"));
+        assert!(favorites.contains("DEN"));
+        favorites.cycle("DEN", "KC").unwrap();
+        assert!(!favorites.contains("DEN"));
+        assert!(favorites.contains("KC"));
+
+        // Configured away team: the home team can still be starred and cleared
+        let mut favorites = Favorites::load(&["kc".to_string()], None);
+        favorites.cycle("KC", "DEN").unwrap();
+        assert!(favorites.contains("KC") && favorites.contains("DEN"));
+        favorites.cycle("KC", "DEN").unwrap();
+        assert!(favorites.contains("KC") && !favorites.contains("DEN"));
+    }
+}
@@ -0,0 +1,58 @@
+use ratatui::buffer::Buffer;
+
+/// The plain-ASCII stand-in for a drawing character, or `None` to leave it alone.
+/// Letters with accents (player and venue names) aren't touched.
+fn ascii_for(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        '┆' | '┊' | '╎' => ":",
+        '─' | '━' | '═' | '┄' | '┅' | '┈' | '┉' | '╌' | '╍' => "-",
+        '│' | '┃' | '║' | '┇' | '┋' | '╏' => "|",
+        // The rest of the box drawing block: corners, tees and crosses
+        '\u{2500}'..='\u{257f}' => "+",
+        '·' => ".",
+        '•' | '●' | '★' => "*",
+        '▶' => ">",
+        '◀' => "<",
+        '▲' => "^",
+        '▼' => "v",
+        '🏈' => "o",
+        // Block elements (big scores, bars) and Braille (logos)
+        '\u{2580}'..='\u{259f}' => "#",
+        '\u{2800}' => " ",
+        '\u{2801}'..='\u{28ff}' => "#",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Swaps every drawing character in a drawn frame for ASCII, for terminals or fonts
+/// that render unicode poorly. Like the color fallback, the widgets draw as usual.
+pub fn to_ascii(buf: &mut Buffer) {
+    for cell in &mut buf.content {
+        let mut chars = cell.symbol().chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            if let Some(ascii) = ascii_for(c) {
+                cell.set_symbol(ascii);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_for() {
+        assert_eq!(ascii_for('─'), Some("-"));
+        assert_eq!(ascii_for('│'), Some("|"));
+        assert_eq!(ascii_for('┌'), Some("+"));
+        assert_eq!(ascii_for('┆'), Some(":"));
+        assert_eq!(ascii_for('🏈'), Some("o"));
+        assert_eq!(ascii_for('▟'), Some("#"));
+        assert_eq!(ascii_for('⣿'), Some("#"));
+        assert_eq!(ascii_for('\u{2800}'), Some(" "));
+        assert_eq!(ascii_for('é'), None);
+        assert_eq!(ascii_for('K'), None);
+    }
+}
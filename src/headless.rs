@@ -0,0 +1,231 @@
+use std::io::Write;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::data::{ScoreboardQuery, ScoreboardSource};
+use crate::model::{Event, GameState, ScoreboardResponse};
+
+/// "LAR 0 @ ATL 7  4:11 - 1st", or "LAR @ ATL  1/4 - 1:00 PM EST" before kickoff.
+pub fn score_line(event: &Event) -> String {
+    let comp = event.competitions.first();
+    let side = |home_away: &str| comp.and_then(|c| c.competitors.iter().find(|c| c.home_away == home_away));
+    let matchup = match (side("away"), side("home")) {
+        (Some(a), Some(h)) if event.status.type_field.state == GameState::Pre => {
+            format!("{} @ {}", a.team.abbreviation, h.team.abbreviation)
+        }
+        (Some(a), Some(h)) => format!(
+            "{} {} @ {} {}",
+            a.team.abbreviation,
+            a.score.as_deref().unwrap_or("0"),
+            h.team.abbreviation,
+            h.score.as_deref().unwrap_or("0"),
+        ),
+        _ => event.short_name.clone(),
+    };
+    format!("{}  {}", matchup, event.status.type_field.short_detail)
+}
+
+/// Plain-text scoreboard for `--once`, one game per line.
+pub fn print_scores(data: &ScoreboardResponse, events: &[&Event], out: &mut impl Write) -> Result<()> {
+    if let Some(week) = &data.week {
+        writeln!(out, "Week {}", week.number)?;
+    }
+    for event in events {
+        writeln!(out, "{}", score_line(event))?;
+    }
+    Ok(())
+}
+
+/// The parsed scoreboard for `--once --output json`, limited to `events`.
+pub fn print_json(data: &ScoreboardResponse, events: &[&Event], out: &mut impl Write) -> Result<()> {
+    let filtered = ScoreboardResponse {
+        events: events.iter().map(|e| (*e).clone()).collect(),
+        ..data.clone()
+    };
+    serde_json::to_writer_pretty(&mut *out, &filtered)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// One status bar update in waybar's custom-module format.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatusModule {
+    pub text: String,
+    pub tooltip: String,
+    pub class: Vec<&'static str>,
+}
+
+impl StatusModule {
+    /// The score of `team`'s game, classed by game state and whether `team` is ahead.
+    pub fn new(events: &[Event], team: &str) -> Self {
+        let Some(event) = events.iter().find(|e| crate::matches_game(e, team)) else {
+            return Self {
+                text: format!("{}: no game", team.to_uppercase()),
+                tooltip: String::new(),
+                class: vec!["none"],
+            };
+        };
+
+        let mut class = vec![match event.status.type_field.state {
+            GameState::Pre => "pre",
+            GameState::In => "live",
+            _ => "final",
+        }];
+        if event.status.type_field.state != GameState::Pre {
+            let competitors = event.competitions.iter().flat_map(|c| &c.competitors);
+            let score = |ours: bool| {
+                competitors
+                    .clone()
+                    .filter(|c| c.team.abbreviation.eq_ignore_ascii_case(team) == ours)
+                    .find_map(|c| c.score.as_deref().and_then(|s| s.parse::<i32>().ok()))
+            };
+            if let (Some(us), Some(them)) = (score(true), score(false)) {
+                class.push(match us.cmp(&them) {
+                    std::cmp::Ordering::Greater => "winning",
+                    std::cmp::Ordering::Less => "losing",
+                    std::cmp::Ordering::Equal => "tied",
+                });
+            }
+        }
+
+        Self {
+            text: score_line(event),
+            tooltip: format!("{}\n{}", event.short_name, event.status.type_field.detail),
+            class,
+        }
+    }
+
+    pub fn print(&self, json: bool, out: &mut impl Write) -> Result<()> {
+        if json {
+            serde_json::to_writer(&mut *out, self)?;
+            writeln!(out)?;
+        } else {
+            writeln!(out, "{}", self.text)?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// `team`'s game with tmux style codes, for `status-right`. Empty when there's no game
+/// so the status line doesn't fill up with placeholders.
+pub fn tmux_line(events: &[Event], team: &str) -> String {
+    let Some(event) = events.iter().find(|e| crate::matches_game(e, team)) else {
+        return String::new();
+    };
+    let comp = event.competitions.first();
+    let side = |home_away: &str| comp.and_then(|c| c.competitors.iter().find(|c| c.home_away == home_away));
+    let (Some(a), Some(h)) = (side("away"), side("home")) else {
+        return event.short_name.clone();
+    };
+
+    let state = &event.status.type_field.state;
+    let team_code = |c: &crate::model::Competitor| {
+        let color = c.team.color.as_deref().map(|hex| format!("fg=#{},", hex)).unwrap_or_default();
+        let score = if *state == GameState::Pre { String::new() } else { format!(" {}", c.score.as_deref().unwrap_or("0")) };
+        format!("#[{}bold]{}#[default]{}", color, c.team.abbreviation, score)
+    };
+    let live = if *state == GameState::In { "#[fg=red]●#[default] " } else { "" };
+    format!(
+        "{}{} #[fg=colour245]@#[default] {} #[fg=colour245]{}#[default]",
+        live,
+        team_code(a),
+        team_code(h),
+        event.status.type_field.short_detail
+    )
+}
+
+/// `--once`: fetches the current slate, optionally narrowed to one game, and prints it.
+pub async fn once(
+    source: &impl ScoreboardSource,
+    league: &str,
+    query: &ScoreboardQuery,
+    game: Option<&str>,
+    json: bool,
+    out: &mut impl Write,
+) -> Result<()> {
+    let data = source.fetch_scoreboard(league, query).await?;
+    let events: Vec<&Event> = data
+        .events
+        .iter()
+        .filter(|e| game.is_none_or(|g| crate::matches_game(e, g)))
+        .collect();
+    if json {
+        print_json(&data, &events, out)
+    } else {
+        print_scores(&data, &events, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::MockSource;
+    use std::fs;
+
+    #[test]
+    fn test_print_scores() {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        let mut out = Vec::new();
+        print_scores(&data, &data.events.iter().collect::<Vec<_>>(), &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Week 17"));
+        assert!(lines.next().unwrap().starts_with("LAR 0 @ ATL 7  "));
+        assert_eq!(text.lines().count(), data.events.len() + 1);
+    }
+
+    #[test]
+    fn test_print_json_round_trips() {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        let mut out = Vec::new();
+        print_json(&data, &[&data.events[0]], &mut out).unwrap();
+
+        let parsed: ScoreboardResponse = serde_json::from_slice(&out).expect("Output should parse back");
+        assert_eq!(parsed.events, vec![data.events[0].clone()]);
+        assert_eq!(parsed.week, data.week);
+    }
+
+    #[test]
+    fn test_status_module() {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+
+        let module = StatusModule::new(&data.events, "atl");
+        assert_eq!(module.class, vec!["live", "winning"]);
+        assert!(module.text.starts_with("LAR 0 @ ATL 7"));
+
+        assert_eq!(StatusModule::new(&data.events, "XYZ").class, vec!["none"]);
+    }
+
+    #[test]
+    fn test_tmux_line() {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+
+        let line = tmux_line(&data.events, "ATL");
+        assert!(line.starts_with("#[fg=red]●#[default] #[fg=#"));
+        assert!(line.contains("ATL#[default] 7"));
+        assert_eq!(tmux_line(&data.events, "XYZ"), "");
+    }
+
+    #[tokio::test]
+    async fn test_once_single_game() {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let source = MockSource { scoreboard: serde_json::from_str(&content).ok(), ..Default::default() };
+        let mut out = Vec::new();
+        once(&source, "nfl", &ScoreboardQuery::default(), Some("atl"), false, &mut out).await.unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_once_unreachable() {
+        let mut out = Vec::new();
+        assert!(once(&MockSource::default(), "nfl", &ScoreboardQuery::default(), None, false, &mut out).await.is_err());
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Next,
+    Previous,
+    ToggleLogos,
+    ToggleLiveFilter,
+    ToggleSidebar,
+    SwitchLeague,
+    TogglePlays,
+    ToggleBoxScore,
+    ToggleStandings,
+    ScrollDown,
+    ScrollUp,
+    PrevWeek,
+    NextWeek,
+    ToggleHelp,
+    Dismiss,
+    ToggleFavorite,
+    ToggleNews,
+    CycleSort,
+    ToggleTicker,
+    ToggleRotate,
+    ToggleRedZone,
+    PickGroup,
+    ToggleRankings,
+    CycleSeasonType,
+    ToggleBracket,
+    ToggleHeadToHead,
+    Search,
+    JumpToTeam,
+    Command,
+    NextLeague,
+    PreviousLeague,
+    ToggleSplit,
+    ToggleFullscreen,
+    ToggleMute,
+}
+
+impl Action {
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Next => "Next game",
+            Action::Previous => "Previous game",
+            Action::ToggleLogos => "Toggle logos",
+            Action::ToggleLiveFilter => "Toggle live games only",
+            Action::ToggleSidebar => "Toggle sidebar",
+            Action::SwitchLeague => "Switch league (NFL/NCAA)",
+            Action::TogglePlays => "Play-by-play",
+            Action::ToggleBoxScore => "Box score",
+            Action::ToggleStandings => "Standings",
+            Action::ScrollDown => "Scroll down",
+            Action::ScrollUp => "Scroll up",
+            Action::PrevWeek => "Previous week",
+            Action::NextWeek => "Next week",
+            Action::ToggleHelp => "Toggle this help",
+            Action::Dismiss => "Close overlay",
+            Action::ToggleFavorite => "Favorite away/home/neither",
+            Action::ToggleNews => "Headlines",
+            Action::CycleSort => "Cycle sidebar sort order",
+            Action::ToggleTicker => "Toggle score ticker",
+            Action::ToggleRotate => "Auto-rotate live games",
+            Action::ToggleRedZone => "RedZone mode",
+            Action::PickGroup => "Pick conference (NCAA)",
+            Action::ToggleRankings => "Top 25 polls (NCAA)",
+            Action::CycleSeasonType => "Cycle preseason / regular / postseason",
+            Action::ToggleBracket => "Playoff bracket (NFL) / bowls (NCAA)",
+            Action::ToggleHeadToHead => "Head-to-head history",
+            Action::Search => "Search games by team",
+            Action::JumpToTeam => "Jump to a team's game",
+            Action::Command => "Command line (:league, :week, :interval, :filter, :quit)",
+            Action::NextLeague => "Next league tab",
+            Action::PreviousLeague => "Previous league tab",
+            Action::ToggleSplit => "Split view: pin this game beside the selected one",
+            Action::ToggleFullscreen => "Fullscreen game (hide everything else)",
+            Action::ToggleMute => "Mute sound alerts",
+        }
+    }
+}
+
+// Order is the order bindings are listed in the help overlay
+const DEFAULT_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::Quit, &["q"]),
+    (Action::Next, &["j", "Down"]),
+    (Action::Previous, &["k", "Up"]),
+    (Action::ToggleLogos, &["l"]),
+    (Action::ToggleLiveFilter, &["f"]),
+    (Action::CycleSort, &["s"]),
+    (Action::Search, &["/"]),
+    (Action::JumpToTeam, &["'"]),
+    (Action::Command, &[":"]),
+    (Action::ToggleSidebar, &["b"]),
+    (Action::ToggleFullscreen, &["F"]),
+    (Action::ToggleTicker, &["t"]),
+    (Action::ToggleRotate, &["a"]),
+    (Action::ToggleRedZone, &["z"]),
+    (Action::SwitchLeague, &["c", "L"]),
+    (Action::NextLeague, &["Tab"]),
+    (Action::PreviousLeague, &["BackTab"]),
+    (Action::PickGroup, &["C"]),
+    (Action::TogglePlays, &["p"]),
+    (Action::ToggleBoxScore, &["x"]),
+    (Action::ToggleSplit, &["v"]),
+    (Action::ToggleHeadToHead, &["h"]),
+    (Action::ToggleStandings, &["S"]),
+    (Action::ToggleNews, &["n"]),
+    (Action::ToggleRankings, &["r"]),
+    (Action::ToggleBracket, &["B"]),
+    (Action::ScrollDown, &["PageDown"]),
+    (Action::ScrollUp, &["PageUp"]),
+    (Action::PrevWeek, &["["]),
+    (Action::NextWeek, &["]"]),
+    (Action::CycleSeasonType, &["T"]),
+    (Action::ToggleHelp, &["?"]),
+    (Action::Dismiss, &["Esc"]),
+    (Action::ToggleFavorite, &["*"]),
+    (Action::ToggleMute, &["m"]),
+];
+
+/// One or more key sequences bound to an action, as written in the config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySpec {
+    fn sequences(&self) -> Vec<&str> {
+        match self {
+            KeySpec::One(s) => vec![s.as_str()],
+            KeySpec::Many(v) => v.iter().map(|s| s.as_str()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyPress {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyPress {
+    fn from_event(key: KeyEvent) -> Self {
+        // Shift is already reflected in the character itself
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+        Self { code: key.code, modifiers }
+    }
+
+    /// Parses a single key like `j`, `S`, `Down`, `PageUp`, `Space` or `C-d`.
+    fn parse(token: &str) -> Result<Self> {
+        let (modifiers, name) = match token.strip_prefix("C-") {
+            Some(rest) if !rest.is_empty() => (KeyModifiers::CONTROL, rest),
+            _ => (KeyModifiers::NONE, token),
+        };
+        let code = match name {
+            "Up" => KeyCode::Up,
+            "Down" => KeyCode::Down,
+            "Left" => KeyCode::Left,
+            "Right" => KeyCode::Right,
+            "PageUp" => KeyCode::PageUp,
+            "PageDown" => KeyCode::PageDown,
+            "Home" => KeyCode::Home,
+            "End" => KeyCode::End,
+            "Enter" => KeyCode::Enter,
+            "Esc" => KeyCode::Esc,
+            "Tab" => KeyCode::Tab,
+            "BackTab" => KeyCode::BackTab,
+            "Backspace" => KeyCode::Backspace,
+            "Space" => KeyCode::Char(' '),
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => bail!("Unknown key '{}'", token),
+                }
+            }
+        };
+        Ok(Self { code, modifiers })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Lookup {
+    Matched(Action),
+    Prefix,
+    None,
+}
+
+/// Translates key presses into [`Action`]s, including multi-key sequences like `g s`.
+#[derive(Debug)]
+pub struct KeyMap {
+    bindings: HashMap<Vec<KeyPress>, Action>,
+    labels: Vec<(Action, Vec<String>)>,
+    pending: Vec<KeyPress>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::new(&HashMap::new()).expect("default bindings are valid")
+    }
+}
+
+impl KeyMap {
+    /// Builds the map from the defaults, replacing any action listed in `overrides`.
+    pub fn new(overrides: &HashMap<Action, KeySpec>) -> Result<Self> {
+        let mut bindings = HashMap::new();
+        let mut labels = vec![];
+        for (action, defaults) in DEFAULT_BINDINGS {
+            let sequences = match overrides.get(action) {
+                Some(spec) => spec.sequences(),
+                None => defaults.to_vec(),
+            };
+            labels.push((*action, sequences.iter().map(|s| s.to_string()).collect()));
+            for sequence in sequences {
+                let keys = sequence
+                    .split_whitespace()
+                    .map(KeyPress::parse)
+                    .collect::<Result<Vec<_>>>()?;
+                if keys.is_empty() {
+                    bail!("Empty key binding for {:?}", action);
+                }
+                if let Some(existing) = bindings.insert(keys, *action) {
+                    bail!("'{}' is bound to both {:?} and {:?}", sequence, existing, action);
+                }
+            }
+        }
+
+        // A binding that prefixes another would fire before the longer one could be typed
+        for keys in bindings.keys() {
+            if let Some(longer) = bindings.keys().find(|other| other.len() > keys.len() && other.starts_with(keys)) {
+                bail!("{:?} shadows the key sequence for {:?}", bindings[keys], bindings[longer]);
+            }
+        }
+
+        Ok(Self { bindings, labels, pending: Vec::new() })
+    }
+
+    /// Feeds a key event, returning an action once a full sequence has been typed.
+    pub fn handle(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        self.pending.push(KeyPress::from_event(key));
+
+        match self.lookup() {
+            Lookup::Matched(action) => {
+                self.pending.clear();
+                Some(action)
+            }
+            Lookup::Prefix => None,
+            Lookup::None if self.pending.len() > 1 => {
+                // A dead-end sequence shouldn't swallow the key that broke it
+                self.pending.clear();
+                self.handle(key)
+            }
+            Lookup::None => {
+                self.pending.clear();
+                None
+            }
+        }
+    }
+
+    /// Every action with the key sequences bound to it, as written in config.
+    pub fn labels(&self) -> &[(Action, Vec<String>)] {
+        &self.labels
+    }
+
+    /// The first key sequence bound to `action`, for hints.
+    pub fn key_for(&self, action: Action) -> Option<&str> {
+        self.labels.iter().find(|(a, _)| *a == action).and_then(|(_, keys)| keys.first()).map(|k| k.as_str())
+    }
+
+    fn lookup(&self) -> Lookup {
+        if let Some(action) = self.bindings.get(&self.pending) {
+            return Lookup::Matched(*action);
+        }
+        let is_prefix = self
+            .bindings
+            .keys()
+            .any(|keys| keys.len() > self.pending.len() && keys.starts_with(&self.pending));
+        if is_prefix {
+            Lookup::Prefix
+        } else {
+            Lookup::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_default_bindings() {
+        let mut keymap = KeyMap::default();
+        assert_eq!(keymap.handle(press('q')), Some(Action::Quit));
+        assert_eq!(keymap.handle(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)), Some(Action::Next));
+        assert_eq!(keymap.handle(KeyEvent::new(KeyCode::Char('S'), KeyModifiers::SHIFT)), Some(Action::ToggleStandings));
+        assert_eq!(keymap.handle(KeyEvent::new(KeyCode::Char('L'), KeyModifiers::SHIFT)), Some(Action::SwitchLeague));
+    }
+
+    #[test]
+    fn test_multi_key_sequence() {
+        let overrides = HashMap::from([(Action::ToggleStandings, KeySpec::One("g s".to_string()))]);
+        let mut keymap = KeyMap::new(&overrides).unwrap();
+        assert_eq!(keymap.handle(press('g')), None);
+        assert_eq!(keymap.handle(press('s')), Some(Action::ToggleStandings));
+
+        // Breaking a sequence still lets the breaking key act on its own
+        assert_eq!(keymap.handle(press('g')), None);
+        assert_eq!(keymap.handle(press('q')), Some(Action::Quit));
+    }
+
+    #[test]
+    fn test_key_for() {
+        let overrides = HashMap::from([(Action::ToggleStandings, KeySpec::Many(vec!["g s".to_string(), "S".to_string()]))]);
+        let keymap = KeyMap::new(&overrides).unwrap();
+        assert_eq!(keymap.key_for(Action::ToggleStandings), Some("g s"));
+        assert_eq!(keymap.key_for(Action::Next), Some("j"));
+    }
+
+    #[test]
+    fn test_conflicting_bindings() {
+        let overrides = HashMap::from([(Action::Quit, KeySpec::One("j".to_string()))]);
+        assert!(KeyMap::new(&overrides).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::{collections::HashMap, io::Cursor};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use image::{DynamicImage, GenericImageView, ImageFormat};
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+use serde::Deserialize;
+
+// Logos are downscaled to fit this many pixels on a side as soon as they're decoded.
+// ESPN's are 500px, more than even a graphics protocol needs in a 22-column box.
+const THUMBNAIL_SIZE: u32 = 256;
+
+// Kitty wants the image sent in pieces no bigger than this
+const KITTY_CHUNK: usize = 4096;
+
+/// How team logos are drawn in the game header.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogoStyle {
+    /// The best the terminal supports.
+    #[default]
+    Auto,
+    /// Kitty's graphics protocol (also WezTerm and Ghostty).
+    Kitty,
+    /// iTerm2's inline images (also WezTerm).
+    Iterm2,
+    /// Upper-half blocks, two colored pixels per cell. Needs truecolor to look right.
+    HalfBlock,
+    /// A Braille-dot silhouette that works everywhere.
+    Braille,
+}
+
+impl LogoStyle {
+    /// Turns `Auto` into a real style, going by the variables terminals set. Sixel
+    /// terminals get Braille; tmux passes none of these through, so it does too.
+    pub fn resolve(self) -> Self {
+        if self != LogoStyle::Auto {
+            return self;
+        }
+        let term = std::env::var("TERM").unwrap_or_default();
+        let program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+        Self::from_env(&term, &program, std::env::var_os("KITTY_WINDOW_ID").is_some())
+    }
+
+    fn from_env(term: &str, program: &str, kitty_window: bool) -> Self {
+        if kitty_window || term == "xterm-kitty" || term == "xterm-ghostty" || program == "WezTerm" || program == "ghostty" {
+            LogoStyle::Kitty
+        } else if program == "iTerm.app" {
+            LogoStyle::Iterm2
+        } else {
+            LogoStyle::Braille
+        }
+    }
+
+    /// Whether logos are written straight to the terminal after the frame is drawn.
+    pub fn is_graphics(self) -> bool {
+        matches!(self, LogoStyle::Kitty | LogoStyle::Iterm2)
+    }
+}
+
+/// Decodes a downloaded logo and shrinks it to a thumbnail. This is CPU work, so the
+/// fetch task runs it with `spawn_blocking`.
+pub fn decode(bytes: &[u8]) -> image::ImageResult<DynamicImage> {
+    let img = image::load_from_memory(bytes)?;
+    if img.width() <= THUMBNAIL_SIZE && img.height() <= THUMBNAIL_SIZE {
+        return Ok(img);
+    }
+    Ok(img.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE))
+}
+
+/// The logo as base64 PNG, the way both graphics protocols take it.
+pub fn encode(img: &DynamicImage) -> Option<String> {
+    let mut png = Vec::new();
+    img.write_to(&mut Cursor::new(&mut png), ImageFormat::Png).ok()?;
+    Some(STANDARD.encode(png))
+}
+
+/// The largest square-looking box that fits in `area`, centered. Cells are about
+/// twice as tall as they are wide.
+pub fn fit(area: Rect) -> Rect {
+    let width = area.width.min(area.height * 2);
+    let height = width / 2;
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+/// The logo's opaque pixels, one per Braille dot of a `width` x `height` cell area,
+/// grouped by color so the canvas draws each color once. `(0, 0)` is the bottom left.
+pub type BraillePoints = Vec<(Color, Vec<(f64, f64)>)>;
+
+pub fn braille_points(img: &DynamicImage, width: u16, height: u16) -> BraillePoints {
+    let (cols, rows) = (width as u32 * 2, height as u32 * 4);
+    let (w, h) = img.dimensions();
+    let mut by_color: HashMap<Color, Vec<(f64, f64)>> = HashMap::new();
+    for y in 0..rows {
+        for x in 0..cols {
+            let p = img.get_pixel(x * w / cols, y * h / rows);
+            if p[3] > 128 {
+                by_color.entry(Color::Rgb(p[0], p[1], p[2])).or_default().push((x as f64, (rows - 1 - y) as f64));
+            }
+        }
+    }
+    by_color.into_iter().collect()
+}
+
+/// Draws a logo with `▀`, the top pixel in the foreground and the bottom one in the
+/// background. Transparent pixels leave the cell's background (the team color) showing.
+pub struct HalfBlocks<'a>(pub &'a DynamicImage);
+
+impl Widget for HalfBlocks<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = fit(area);
+        let (w, h) = self.0.dimensions();
+        let pixel = |x: u16, y: u16| {
+            let p = self.0.get_pixel(x as u32 * w / area.width as u32, y as u32 * h / (area.height as u32 * 2));
+            (p[3] > 128).then_some(Color::Rgb(p[0], p[1], p[2]))
+        };
+        for row in 0..area.height {
+            for col in 0..area.width {
+                let cell = &mut buf[(area.x + col, area.y + row)];
+                match (pixel(col, row * 2), pixel(col, row * 2 + 1)) {
+                    (Some(top), Some(bottom)) => {
+                        cell.set_char('▀').set_fg(top).set_bg(bottom);
+                    }
+                    (Some(top), None) => {
+                        cell.set_char('▀').set_fg(top);
+                    }
+                    (None, Some(bottom)) => {
+                        cell.set_char('▄').set_fg(bottom);
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+    }
+}
+
+/// The escape sequence that draws `payload` over `width` x `height` cells at the cursor.
+pub fn escape(style: LogoStyle, payload: &str, width: u16, height: u16) -> String {
+    match style {
+        LogoStyle::Kitty => {
+            // Underneath the text (z=-1) so popups still cover it, and the cursor stays put
+            let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+            let mut out = String::new();
+            for (i, chunk) in chunks.iter().enumerate() {
+                let more = u8::from(i + 1 < chunks.len());
+                let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+                if i == 0 {
+                    out.push_str(&format!("\x1b_Ga=T,f=100,q=2,C=1,z=-1,c={},r={},m={};{}\x1b\\", width, height, more, chunk));
+                } else {
+                    out.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+                }
+            }
+            out
+        }
+        LogoStyle::Iterm2 => format!("\x1b]1337;File=inline=1;width={};height={};preserveAspectRatio=1:{}\x07", width, height, payload),
+        LogoStyle::Auto | LogoStyle::HalfBlock | LogoStyle::Braille => String::new(),
+    }
+}
+
+/// Removes every logo Kitty has on screen. iTerm2 images go away when the cells are
+/// redrawn, so there's nothing to send for it.
+pub fn clear(style: LogoStyle) -> &'static str {
+    match style {
+        LogoStyle::Kitty => "\x1b_Ga=d,d=A,q=2\x1b\\",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(LogoStyle::from_env("xterm-kitty", "", false), LogoStyle::Kitty);
+        assert_eq!(LogoStyle::from_env("xterm-256color", "WezTerm", false), LogoStyle::Kitty);
+        assert_eq!(LogoStyle::from_env("xterm-256color", "iTerm.app", false), LogoStyle::Iterm2);
+        assert_eq!(LogoStyle::from_env("tmux-256color", "tmux", false), LogoStyle::Braille);
+        assert_eq!(LogoStyle::Braille.resolve(), LogoStyle::Braille);
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit(Rect::new(0, 0, 22, 8)), Rect::new(3, 0, 16, 8));
+        assert_eq!(fit(Rect::new(10, 2, 22, 20)), Rect::new(10, 6, 22, 11));
+    }
+
+    #[test]
+    fn test_decode_thumbnail() {
+        let mut png = Vec::new();
+        DynamicImage::ImageRgba8(image::RgbaImage::new(500, 250)).write_to(&mut Cursor::new(&mut png), ImageFormat::Png).unwrap();
+        let img = decode(&png).unwrap();
+        assert_eq!(img.dimensions(), (256, 128));
+        assert!(decode(b"not a png").is_err());
+    }
+
+    #[test]
+    fn test_braille_points() {
+        let mut img = image::RgbaImage::new(4, 4);
+        img.put_pixel(0, 0, image::Rgba([255, 0, 0, 255]));
+        let points = braille_points(&DynamicImage::ImageRgba8(img), 1, 1);
+        // One cell is 2x4 dots; the top-left pixel covers the top-left dot
+        assert_eq!(points, vec![(Color::Rgb(255, 0, 0), vec![(0.0, 3.0)])]);
+    }
+
+    #[test]
+    fn test_half_blocks() {
+        // Opaque red on top, transparent below
+        let mut img = image::RgbaImage::new(2, 2);
+        img.put_pixel(0, 0, image::Rgba([255, 0, 0, 255]));
+        img.put_pixel(1, 0, image::Rgba([255, 0, 0, 255]));
+        let img = DynamicImage::ImageRgba8(img);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf.set_style(buf.area, ratatui::style::Style::default().bg(Color::Blue));
+        HalfBlocks(&img).render(buf.area, &mut buf);
+        assert_eq!(buf[(0, 0)].symbol(), "▀");
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(255, 0, 0));
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+    }
+
+    #[test]
+    fn test_kitty_chunks() {
+        let payload = "A".repeat(KITTY_CHUNK + 10);
+        let out = escape(LogoStyle::Kitty, &payload, 16, 8);
+        assert!(out.starts_with("\x1b_Ga=T,f=100,q=2,C=1,z=-1,c=16,r=8,m=1;"));
+        assert!(out.ends_with(&format!("\x1b_Gm=0;{}\x1b\\", "A".repeat(10))));
+    }
+}
@@ -1,38 +1,389 @@
+mod cache;
+mod color;
+mod command;
+mod config;
 mod data;
+mod demo;
+mod favorites;
+mod glyphs;
+mod headless;
+mod keymap;
+mod logo;
 mod model;
-
-use std::{collections::HashMap, error::Error, io, time::Duration};
+mod notify;
+mod replay;
+mod views;
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    error::Error,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant, SystemTime},
+};
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    cursor::MoveTo,
+    execute, queue,
+    style::Print,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use data::DataClient;
-use image::{DynamicImage, GenericImageView};
-use model::Event as GameEvent;
+use cache::ScoreboardCache;
+use color::ColorSupport;
+use config::Config;
+use data::{Bracket, DataClient, ScoreboardQuery, ScoreboardSource, SeasonType};
+use favorites::Favorites;
+use keymap::{Action, KeyMap};
+use logo::LogoStyle;
+use notify::{CloseGameConfig, Notifier, SoundConfig};
+use image::DynamicImage;
+use model::{Competitor, Coverage, Event as GameEvent, GameState, LeaderCategory, News, Odds, Rankings, ScoreboardResponse, SeriesEvent, Standings, Summary, TeamOdds};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect},
     style::{Color, Modifier, Style, Stylize},
     symbols::Marker,
     text::{Line, Span},
-    widgets::{canvas::{Canvas, Points}, Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{canvas::{Canvas, Points}, Block, Borders, List, ListItem, ListState, Paragraph, Row, Table, Tabs, Wrap},
     Frame, Terminal,
 };
-use tokio::sync::mpsc;
+use tokio::{sync::mpsc, task::JoinSet};
+use tracing::{debug, info, warn};
 use tui_big_text::{BigText, PixelSize};
+use views::toasts::Toast;
+
+// Messages from the background fetch task to the UI loop
+enum Update {
+    Scoreboard(ScoreboardResponse),
+    Logo(String, DynamicImage),
+    Summary(String, Box<Summary>),
+    // The scoring plays behind a score change, e.g. "TD LAR: K.Williams 23 Yd pass ..."
+    Scored(String, String),
+    Standings(Standings),
+    News(News),
+    Rankings(Rankings),
+    Bracket(Bracket),
+    Bowls(Vec<GameEvent>),
+    HeadToHead(String, Vec<SeriesEvent>),
+    // The current slate of a league tab other than the one being shown
+    LeagueScoreboard(String, Vec<GameEvent>),
+    FetchError(FetchError),
+    BadResponse(BadResponseNotice),
+}
+
+// The scoreboard couldn't be fetched; the fetch task will try again after `retry_in`
+#[derive(Debug, Clone)]
+struct FetchError {
+    message: String,
+    retry_in: Duration,
+}
+
+// An endpoint answered with something that didn't parse. Retrying won't change that,
+// so it's shown apart from FetchError
+#[derive(Debug, Clone, PartialEq)]
+struct BadResponseNotice {
+    endpoint: &'static str,
+    message: String,
+}
+
+// Below either of these the game panel switches to its compact layout
+const COMPACT_WIDTH: u16 = 80;
+const COMPACT_HEIGHT: u16 = 30;
+
+// How long a toast stays up, and how many can be queued at once
+const TOAST_DURATION: Duration = Duration::from_secs(6);
+const TOAST_LIMIT: usize = 4;
+
+// How long the RED ZONE and TURNOVER badges blink
+const FLASH_DURATION: Duration = Duration::from_secs(5);
+
+// With nothing animating, the screen is still redrawn this often for the clocks
+const CLOCK_TICK: Duration = Duration::from_secs(1);
+
+// After this long without input and with no live games, the loop waits up to
+// IDLE_POLL for events instead of TICK
+const IDLE_AFTER: Duration = Duration::from_secs(60);
+const TICK: Duration = Duration::from_millis(100);
+const IDLE_POLL: Duration = Duration::from_secs(1);
+
+// Logos downloading at once, enough to fill a college slate quickly without hammering ESPN
+const LOGO_DOWNLOADS: usize = 8;
+
+// Optional data the UI currently needs from the fetch task beyond the scoreboard
+#[derive(Debug, Default, Clone, PartialEq)]
+struct FetchWants {
+    summary_event: Option<String>,
+    standings: bool,
+    news: bool,
+    rankings: bool,
+    bracket: bool,
+    bowls: bool,
+    // Team ids and the season to look back from
+    head_to_head: Option<(String, String, i32)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Game,
+    Plays,
+    BoxScore,
+    Standings,
+    News,
+    Rankings,
+    Bracket,
+    Bowls,
+    HeadToHead,
+}
+
+impl View {
+    // Per-game views, which all draw on the summary endpoint (drive chart, plays, stats)
+    fn needs_summary(self) -> bool {
+        matches!(self, View::Game | View::Plays | View::BoxScore)
+    }
+
+    // Keys worth hinting at in the bottom bar; a view's own toggle takes you back
+    fn hints(self) -> &'static [(Action, &'static str)] {
+        match self {
+            View::Game => &[
+                (Action::Next, "next"),
+                (Action::Previous, "prev"),
+                (Action::ToggleLiveFilter, "live"),
+                (Action::TogglePlays, "plays"),
+                (Action::ToggleBoxScore, "box score"),
+                (Action::ToggleFavorite, "favorite"),
+                (Action::ToggleHelp, "help"),
+                (Action::Quit, "quit"),
+            ],
+            View::Plays => &[(Action::TogglePlays, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::BoxScore => &[(Action::ToggleBoxScore, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::Standings => &[(Action::ToggleStandings, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::News => &[(Action::ToggleNews, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::Rankings => &[(Action::ToggleRankings, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::Bracket | View::Bowls => &[(Action::ToggleBracket, "back"), (Action::ScrollDown, "scroll"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+            View::HeadToHead => &[(Action::ToggleHeadToHead, "back"), (Action::Next, "next game"), (Action::ToggleHelp, "help"), (Action::Quit, "quit")],
+        }
+    }
+
+    // Views that need the field's space for a taller details pane
+    fn hides_field(self) -> bool {
+        matches!(self, View::Plays | View::BoxScore)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortMode {
+    // ESPN's own order, which is by kickoff
+    Kickoff,
+    State,
+    Closest,
+    Alphabetical,
+}
+
+impl SortMode {
+    fn next(self) -> Self {
+        match self {
+            SortMode::Kickoff => SortMode::State,
+            SortMode::State => SortMode::Closest,
+            SortMode::Closest => SortMode::Alphabetical,
+            SortMode::Alphabetical => SortMode::Kickoff,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortMode::Kickoff => "kickoff",
+            SortMode::State => "state",
+            SortMode::Closest => "closest",
+            SortMode::Alphabetical => "a-z",
+        }
+    }
+
+    // Sorts indexes into `events`. Stable, so ties keep ESPN's order and refreshes don't shuffle them
+    fn sort(self, indices: &mut [usize], events: &[GameEvent]) {
+        let event = |i: &usize| &events[*i];
+        match self {
+            SortMode::Kickoff => indices.sort_by(|a, b| event(a).date.cmp(&event(b).date)),
+            // Live first, then upcoming, then finished
+            SortMode::State => indices.sort_by_key(|i| match event(i).status.type_field.state {
+                GameState::In => 0,
+                GameState::Pre => 1,
+                _ => 2,
+            }),
+            // Games that haven't started have no margin and go last
+            SortMode::Closest => indices.sort_by_key(|i| score_margin(event(i)).unwrap_or(u32::MAX)),
+            SortMode::Alphabetical => indices.sort_by(|a, b| event(a).short_name.cmp(&event(b).short_name)),
+        }
+    }
+}
+
+/// Whether `query` is the event's id or either team's abbreviation.
+fn matches_game(event: &GameEvent, query: &str) -> bool {
+    event.id == query
+        || event
+            .competitions
+            .iter()
+            .flat_map(|c| &c.competitors)
+            .any(|c| c.team.abbreviation.eq_ignore_ascii_case(query))
+}
+
+fn is_red_zone(event: &GameEvent) -> bool {
+    event.status.type_field.state == GameState::In
+        && event
+            .competitions
+            .first()
+            .and_then(|c| c.situation.as_ref())
+            .and_then(|s| s.is_red_zone)
+            .unwrap_or(false)
+}
+
+/// The game clock, run down locally since the last refresh while play is underway.
+/// Stoppages between polls mean it can run ahead; the next refresh puts it right.
+fn game_clock(event: &GameEvent, refreshed_at: Option<Instant>) -> String {
+    let status = &event.status;
+    match (status.clock, refreshed_at) {
+        (Some(clock), Some(at)) if status.type_field.state == GameState::In && status.type_field.description == "In Progress" => {
+            let remaining = (clock - at.elapsed().as_secs_f64()).max(0.0).ceil() as u64;
+            format!("{}:{:02}", remaining / 60, remaining % 60)
+        }
+        _ => status.display_clock.clone(),
+    }
+}
+
+// Time left until kickoff: "2d 4h", "1h 23m", or to the second in the last hour
+fn countdown(kickoff: chrono::DateTime<chrono::Utc>) -> String {
+    let secs = (kickoff - chrono::Utc::now()).num_seconds().max(0);
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3600, secs % 3600 / 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, secs % 60)
+    }
+}
+
+fn score_margin(event: &GameEvent) -> Option<u32> {
+    if event.status.type_field.state == GameState::Pre {
+        return None;
+    }
+    let comp = event.competitions.first()?;
+    let mut scores = comp
+        .competitors
+        .iter()
+        .map(|c| c.score.as_deref().and_then(|s| s.parse::<i32>().ok()).unwrap_or(0));
+    Some(scores.next()?.abs_diff(scores.next()?))
+}
 
 #[derive(Debug)]
 struct App {
     should_quit: bool,
     events: Vec<GameEvent>,
+    // Indexes into `events` of the games in the sidebar, in order (see `refilter`)
+    visible: Vec<usize>,
     state: ListState,
     filter_live: bool,
     pub logos: HashMap<String, DynamicImage>,
     pub show_logos: bool,
+    pub logo_style: LogoStyle,
+    // Where this frame wants logos drawn with a graphics protocol, and where they are now
+    pub logo_spots: RefCell<Vec<(String, Rect)>>,
+    pub placed_logos: Vec<(String, Rect)>,
+    // Braille dots per team and area size, and base64 PNG per team for graphics protocols
+    pub logo_points: RefCell<HashMap<(String, u16, u16), logo::BraillePoints>>,
+    pub logo_payloads: HashMap<String, String>,
     pub league_label: String,
+    pub week: Option<u32>,
     pub show_sidebar: bool,
+    pub view: View,
+    pub summaries: HashMap<String, Summary>,
+    pub standings: Option<Standings>,
+    pub rankings: Option<Rankings>,
+    pub bracket: Option<Bracket>,
+    pub bowls: Option<Vec<GameEvent>>,
+    // Past meetings by matchup key; these don't change, so they're kept across weeks
+    pub head_to_head: HashMap<String, Vec<SeriesEvent>>,
+    pub news: Option<News>,
+    pub detail_scroll: u16,
+    pub keymap: KeyMap,
+    pub show_help: bool,
+    // Seconds between refreshes; shared with the fetch task so `:interval` takes effect
+    pub interval: Arc<AtomicU64>,
+    pub favorites: Favorites,
+    // The favorites as the notifier sees them, kept in step when teams are starred
+    pub followed: Arc<Mutex<BTreeSet<String>>>,
+    // Where the sidebar was last drawn, for mouse hit-testing
+    pub sidebar_area: Option<Rect>,
+    pub sidebar_odds: bool,
+    pub sort_mode: SortMode,
+    pub show_ticker: bool,
+    // Drives animations like the ticker, independent of refreshes
+    pub started: Instant,
+    // Something changed since the last frame; see `run_app` for what else triggers a redraw
+    pub dirty: bool,
+    // The last key press or mouse event, for backing off when nobody's watching
+    pub last_input: Instant,
+    pub rotate: bool,
+    pub rotate_secs: u64,
+    pub last_rotate: Instant,
+    pub redzone: bool,
+    // Games in the red zone as of the last refresh, to spot new arrivals
+    pub redzone_games: HashSet<String>,
+    pub redzone_alert: Option<Instant>,
+    // `[sound]` from the config, and whether `m` has silenced it
+    pub sound: Option<SoundConfig>,
+    pub muted: bool,
+    pub close_game: CloseGameConfig,
+    // How long before a favorite's kickoff to toast, and the games already toasted
+    pub kickoff_reminder: Option<chrono::Duration>,
+    pub reminded: HashSet<String>,
+    // When each game's latest turnover was spotted, for the status bar flash
+    pub turnovers: HashMap<String, Instant>,
+    // Where each game's line of scrimmage was before it last moved, and when, to slide the marker
+    pub scrimmage_moves: HashMap<String, (f64, Instant)>,
+    // When the last scoreboard arrived, to run game clocks down until the next one
+    pub refreshed_at: Option<Instant>,
+    // Transient messages in the corner, oldest first, each with when it was raised
+    pub toasts: VecDeque<(Toast, Instant)>,
+    // Sidebar search text, and whether the prompt is still taking keys
+    pub search: Option<String>,
+    pub searching: bool,
+    // A team abbreviation being typed to jump to, while that prompt is open
+    pub jump: Option<String>,
+    // The `:` command being typed, while the command line is open
+    pub command: Option<String>,
+    // League tabs, and the last scores seen for each so switching is instant
+    pub leagues: Vec<String>,
+    pub league_boards: HashMap<String, Vec<GameEvent>>,
+    // The game pinned to the left of the split view, while it's on
+    pub split: Option<String>,
+    // Hides the sidebar, tabs, ticker and hint bar without changing their own settings
+    pub fullscreen: bool,
+    // Set each frame when the terminal is too small for the full game layout
+    pub compact: bool,
+    // What the terminal can show; RGB colors are mapped down to it after each draw
+    pub color_support: ColorSupport,
+    pub ascii: bool,
+    pub possession_marker: String,
+    // `--game`: a team abbreviation or event id to show on its own
+    pub watch: Option<String>,
+    // `--team`: whose game to select, until it shows up in the list
+    pub start_team: Option<String>,
+    // The last failed fetch and when it was reported, cleared by the next success
+    pub fetch_error: Option<(FetchError, Instant)>,
+    // Shown for a couple of refreshes; a response that's still bad is reported again each time
+    bad_response: Option<(BadResponseNotice, Instant)>,
+    // Set while showing scores from the on-disk cache rather than a live fetch
+    pub cached_at: Option<SystemTime>,
+    // College football conference filter, and the picker for it while open
+    pub group: Option<String>,
+    pub group_picker: Option<ListState>,
+    // Season type being browsed; `None` follows ESPN's current one
+    pub season_type: Option<SeasonType>,
 }
 
 impl Default for App {
@@ -40,12 +391,75 @@ impl Default for App {
         Self {
             should_quit: false,
             events: Vec::new(),
+            visible: Vec::new(),
             state: ListState::default(),
             filter_live: false,
             logos: HashMap::new(),
             show_logos: true,
+            logo_style: LogoStyle::Braille,
+            logo_spots: RefCell::new(vec![]),
+            placed_logos: vec![],
+            logo_points: RefCell::new(HashMap::new()),
+            logo_payloads: HashMap::new(),
             league_label: "loading...".to_string(),
+            week: None,
             show_sidebar: true,
+            view: View::Game,
+            summaries: HashMap::new(),
+            standings: None,
+            rankings: None,
+            bracket: None,
+            bowls: None,
+            head_to_head: HashMap::new(),
+            news: None,
+            detail_scroll: 0,
+            keymap: KeyMap::default(),
+            show_help: false,
+            interval: Arc::new(AtomicU64::new(15)),
+            favorites: Favorites::default(),
+            followed: Arc::default(),
+            sidebar_area: None,
+            sidebar_odds: false,
+            sort_mode: SortMode::Kickoff,
+            show_ticker: false,
+            started: Instant::now(),
+            dirty: true,
+            last_input: Instant::now(),
+            rotate: false,
+            rotate_secs: 10,
+            last_rotate: Instant::now(),
+            redzone: false,
+            redzone_games: HashSet::new(),
+            redzone_alert: None,
+            sound: None,
+            muted: false,
+            close_game: CloseGameConfig::default(),
+            kickoff_reminder: None,
+            reminded: HashSet::new(),
+            turnovers: HashMap::new(),
+            scrimmage_moves: HashMap::new(),
+            refreshed_at: None,
+            toasts: VecDeque::new(),
+            search: None,
+            searching: false,
+            jump: None,
+            command: None,
+            leagues: Vec::new(),
+            league_boards: HashMap::new(),
+            split: None,
+            fullscreen: false,
+            compact: false,
+            color_support: ColorSupport::TrueColor,
+            ascii: false,
+            possession_marker: "🏈".to_string(),
+            watch: None,
+            start_team: None,
+            fetch_error: None,
+            bad_response: None,
+            cached_at: None,
+            group: None,
+            group_picker: None,
+            season_type: None,
         }
     }
 }
@@ -57,22 +471,12 @@ impl App {
         app
     }
 
-    fn on_key(&mut self, c: char) {
-        match c {
-            'q' => self.should_quit = true,
-            'j' => self.next(),
-            'k' => self.previous(),
-            'l' => self.toggle_live_filter(),
-            _ => {}
-        }
-    }
-
     fn next(&mut self) {
-        if self.filtered_events().is_empty() { return; }
+        if self.visible.is_empty() { return; }
         
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.filtered_events().len() - 1 {
+                if i >= self.visible.len() - 1 {
                     0
                 } else {
                     i + 1
@@ -84,12 +488,12 @@ impl App {
     }
 
     fn previous(&mut self) {
-        if self.filtered_events().is_empty() { return; }
+        if self.visible.is_empty() { return; }
 
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.filtered_events().len() - 1
+                    self.visible.len() - 1
                 } else {
                     i - 1
                 }
@@ -101,18 +505,291 @@ impl App {
 
     fn toggle_live_filter(&mut self) {
         self.filter_live = !self.filter_live;
+        self.refilter();
         self.state.select(Some(0));
     }
 
-    fn filtered_events(&self) -> Vec<&GameEvent> {
-        if !self.filter_live {
-            self.events.iter().collect()
-        } else {
-            self.events
-                .iter()
-                .filter(|e| e.status.type_field.state == "in")
-                .collect()
+    /// Rebuilds the list of games the sidebar shows. Call it after changing the events
+    /// or anything that filters or sorts them; selection works off the cached list.
+    fn refilter(&mut self) {
+        let mut visible: Vec<usize> = (0..self.events.len()).collect();
+        let events = &self.events;
+        if self.filter_live {
+            visible.retain(|&i| events[i].status.type_field.state == GameState::In);
+        }
+        if let Some(watch) = &self.watch {
+            visible.retain(|&i| matches_game(&events[i], watch));
+        }
+        if let Some(search) = &self.search {
+            visible.retain(|&i| events[i].fuzzy_matches(search));
+        }
+        self.sort_mode.sort(&mut visible, events);
+        // Pin favorites to the top; stable so the rest keep the chosen order
+        if !self.favorites.is_empty() {
+            visible.sort_by_key(|&i| !self.is_favorite_game(&events[i]));
+        }
+        self.visible = visible;
+    }
+
+    fn filtered_events(&self) -> impl Iterator<Item = &GameEvent> {
+        self.visible.iter().filter_map(|&i| self.events.get(i))
+    }
+
+    fn filtered_event(&self, index: usize) -> Option<&GameEvent> {
+        self.visible.get(index).and_then(|&i| self.events.get(i))
+    }
+
+    fn is_favorite_game(&self, event: &GameEvent) -> bool {
+        event
+            .competitions
+            .iter()
+            .flat_map(|c| &c.competitors)
+            .any(|c| self.favorites.contains(&c.team.abbreviation))
+    }
+
+    fn cycle_favorite(&mut self) {
+        let Some(event) = self.selected_event() else {
+            return;
+        };
+        let team = |side: &str| {
+            event
+                .competitions
+                .first()
+                .and_then(|c| c.competitors.iter().find(|c| c.home_away == side))
+                .map(|c| c.team.abbreviation.clone())
+        };
+        let (Some(away), Some(home)) = (team("away"), team("home")) else {
+            return;
+        };
+        let id = event.id.clone();
+
+        let _ = self.favorites.cycle(&away, &home);
+        *self.followed.lock().unwrap() = self.favorites.teams();
+        self.refilter();
+        // Keep the same game selected now that it may have moved
+        self.select_event(&id);
+    }
+
+    fn cycle_sort(&mut self) {
+        let id = self.selected_event().map(|e| e.id.clone());
+        self.sort_mode = self.sort_mode.next();
+        self.refilter();
+        if let Some(id) = id {
+            self.select_event(&id);
+        }
+    }
+
+    fn toggle_rotate(&mut self) {
+        self.rotate = !self.rotate;
+        self.last_rotate = Instant::now();
+    }
+
+    /// Moves to the next live game after the selected one, once per rotation period.
+    fn tick_rotate(&mut self) {
+        if !self.rotate || self.last_rotate.elapsed() < Duration::from_secs(self.rotate_secs) {
+            return;
+        }
+        self.last_rotate = Instant::now();
+
+        let current = self.state.selected().unwrap_or(0);
+        let next_live = (1..=self.visible.len())
+            .map(|step| (current + step) % self.visible.len())
+            .find(|&i| self.filtered_event(i).is_some_and(|e| e.status.type_field.state == GameState::In));
+        if let Some(index) = next_live {
+            self.state.select(Some(index));
+            self.detail_scroll = 0;
+            self.dirty = true;
+        }
+    }
+
+    /// In RedZone mode, jumps to a game whose offense just reached the red zone.
+    /// The selection is left alone while it's still in the red zone itself.
+    fn check_red_zone(&mut self) {
+        let current: HashSet<String> = self.events.iter().filter(|e| is_red_zone(e)).map(|e| e.id.clone()).collect();
+        let arrived = current.difference(&self.redzone_games).next().is_some();
+        self.redzone_games = current;
+        if !self.redzone || !arrived || self.selected_event().is_some_and(is_red_zone) {
+            return;
         }
+
+        // Favorites first, then the closest game, then the latest in the game
+        let target = self
+            .filtered_events()
+            .filter(|e| is_red_zone(e))
+            .min_by_key(|e| (!self.is_favorite_game(e), score_margin(e), std::cmp::Reverse(e.status.period)))
+            .map(|e| e.id.clone());
+        if let Some(id) = target {
+            self.select_event(&id);
+            self.detail_scroll = 0;
+            self.redzone_alert = Some(Instant::now());
+        }
+    }
+
+    // Toasts every alert, notes turnovers for the status bar flash, and rings once for
+    // any favorite's alerts the sound config asks for
+    fn check_alerts(&mut self, previous: Vec<GameEvent>) {
+        let previous: HashMap<String, GameEvent> = previous.into_iter().map(|e| (e.id.clone(), e)).collect();
+        let mut alerts = notify::detect(&previous, &self.events);
+        alerts.extend(notify::detect_close_games(&previous, &self.events, &self.close_game));
+        if let Some(lead) = self.kickoff_reminder {
+            let soon = notify::detect_kickoffs_soon(&self.events, lead, chrono::Utc::now(), &mut self.reminded);
+            alerts.extend(soon.into_iter().filter(|a| self.is_favorite_game(&a.event)));
+        }
+        if let Some(sound) = self.sound.as_ref().filter(|_| !self.muted) {
+            if alerts.iter().any(|a| sound.events.contains(&a.kind) && self.is_favorite_game(&a.event)) {
+                if let Err(err) = sound.play() {
+                    warn!(%err, "sound alert failed");
+                }
+            }
+        }
+        for alert in alerts {
+            let color = match alert.kind {
+                notify::AlertKind::Turnover => Color::Magenta,
+                notify::AlertKind::ScoreChange => Color::Green,
+                notify::AlertKind::CloseGame => Color::Yellow,
+                notify::AlertKind::UpsetWatch | notify::AlertKind::Overtime => Color::Red,
+                _ => Color::Cyan,
+            };
+            let mut text = alert.message();
+            // Point at the jump key when a close game isn't the one on screen
+            if alert.kind == notify::AlertKind::CloseGame && self.selected_event().is_none_or(|e| e.id != alert.event.id) {
+                if let Some(key) = self.keymap.key_for(Action::JumpToTeam) {
+                    text.push_str(&format!(" ({} to jump)", key));
+                }
+            }
+            // Score toasts get the scoring play once the summary is in
+            let event_id = (alert.kind == notify::AlertKind::ScoreChange).then(|| alert.event.id.clone());
+            self.push_toast(Toast { text, color, event_id });
+            if alert.kind == notify::AlertKind::Turnover {
+                self.turnovers.insert(alert.event.id, Instant::now());
+            }
+        }
+    }
+
+    fn toast(&mut self, text: String, color: Color) {
+        self.push_toast(Toast { text, color, event_id: None });
+    }
+
+    fn push_toast(&mut self, toast: Toast) {
+        self.toasts.push_back((toast, Instant::now()));
+        if self.toasts.len() > TOAST_LIMIT {
+            self.toasts.pop_front();
+        }
+    }
+
+    // Swaps "Score: ..." for who scored and how, if that game's toast is still up
+    fn fill_in_score(&mut self, event_id: &str, detail: String) {
+        let Some(event) = self.events.iter().find(|e| e.id == event_id) else { return };
+        let alert = notify::Alert { kind: notify::AlertKind::ScoreChange, event: event.clone(), detail: Some(detail) };
+        if let Some((toast, _)) = self.toasts.iter_mut().rev().find(|(t, _)| t.event_id.as_deref() == Some(event_id)) {
+            toast.text = alert.message();
+        }
+    }
+
+    fn expire_toasts(&mut self) {
+        let before = self.toasts.len();
+        self.toasts.retain(|(_, at)| at.elapsed() < TOAST_DURATION);
+        self.dirty |= self.toasts.len() != before;
+    }
+
+    /// Nothing is live and nobody has touched a key in a while, e.g. left running
+    /// overnight in a tmux pane. The loop then polls slowly and stops animating.
+    fn idle(&self) -> bool {
+        self.last_input.elapsed() >= IDLE_AFTER && !self.events.iter().any(|e| e.status.type_field.state == GameState::In)
+    }
+
+    /// Whether something on screen moves by itself and needs drawing every tick,
+    /// rather than just when the state changes or the clocks tick over.
+    fn animating(&self) -> bool {
+        (self.show_ticker && !self.fullscreen)
+            || self.scrimmage_moves.values().any(|(_, at)| at.elapsed() < SCRIMMAGE_SLIDE)
+            || self.redzone_alert.is_some_and(|at| at.elapsed() < FLASH_DURATION)
+            || self.turnovers.values().any(|at| at.elapsed() < FLASH_DURATION)
+    }
+
+    fn check_scrimmage(&mut self, previous: &[GameEvent]) {
+        for event in &self.events {
+            let Some(before) = previous.iter().find(|e| e.id == event.id).and_then(scrimmage_yard) else { continue };
+            if scrimmage_yard(event).is_some_and(|now| now != before) {
+                self.scrimmage_moves.insert(event.id.clone(), (before, Instant::now()));
+            }
+        }
+    }
+
+    /// Selects the game `abbreviation` is playing in, dropping any filter that hides it.
+    fn jump_to_team(&mut self, abbreviation: &str) -> bool {
+        let Some(id) = self.events.iter().find(|e| matches_game(e, abbreviation)).map(|e| e.id.clone()) else {
+            return false;
+        };
+        if !self.filtered_events().any(|e| e.id == id) {
+            self.filter_live = false;
+            self.search = None;
+            self.refilter();
+        }
+        self.select_event(&id);
+        self.detail_scroll = 0;
+        self.rotate = false;
+        true
+    }
+
+    fn select_event(&mut self, id: &str) {
+        let index = self.filtered_events().position(|e| e.id == id);
+        if let Some(index) = index {
+            self.state.select(Some(index));
+        }
+    }
+
+    /// Selects the `--team` game the first time it's in the list. After that the
+    /// selection follows it through refreshes like any other.
+    fn select_start_team(&mut self) {
+        let Some(team) = &self.start_team else { return };
+        let Some(id) = self.filtered_events().find(|e| matches_game(e, team)).map(|e| e.id.clone()) else {
+            return;
+        };
+        self.select_event(&id);
+        self.start_team = None;
+    }
+
+    fn on_mouse(&mut self, mouse: MouseEvent) {
+        let over_sidebar = self
+            .sidebar_area
+            .is_some_and(|area| area.contains((mouse.column, mouse.row).into()));
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) if over_sidebar => {
+                let Some(area) = self.sidebar_area else { return };
+                // Rows start inside the top border; account for the list having scrolled
+                let Some(row) = mouse.row.checked_sub(area.y + 1) else { return };
+                let index = self.state.offset() + row as usize;
+                if index < self.visible.len() {
+                    self.state.select(Some(index));
+                    self.detail_scroll = 0;
+                    self.rotate = false;
+                }
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                let down = mouse.kind == MouseEventKind::ScrollDown;
+                if over_sidebar || self.view == View::Game {
+                    if down { self.next() } else { self.previous() }
+                    self.detail_scroll = 0;
+                    self.rotate = false;
+                } else if down {
+                    self.detail_scroll = self.detail_scroll.saturating_add(1);
+                } else {
+                    self.detail_scroll = self.detail_scroll.saturating_sub(1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn selected_event(&self) -> Option<&GameEvent> {
+        self.filtered_event(self.state.selected().unwrap_or(0))
+    }
+
+    fn toggle_view(&mut self, view: View) {
+        self.view = if self.view == view { View::Game } else { view };
+        self.detail_scroll = 0;
     }
 }
 
@@ -127,12 +804,23 @@ fn parse_color(hex: &str) -> Color {
     }
 }
 
-use clap::Parser;
-use std::sync::Arc;
+// Black or white, whichever reads better on a team color
+fn text_color_on(bg: Color) -> Color {
+    match bg {
+        Color::Rgb(r, g, b) if 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64 > 150.0 => Color::Black,
+        _ => Color::White,
+    }
+}
+
+use clap::{CommandFactory, Parser};
+use std::{path::PathBuf, sync::Arc};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Update interval in seconds
     #[arg(short, long, default_value_t = 15)]
     interval: u64,
@@ -140,12 +828,185 @@ struct Args {
     /// Use NCAA College Football instead of NFL
     #[arg(long)]
     ncaa: bool,
+
+    /// Only show one college conference, by ESPN group id (e.g. 8 for SEC, 5 for Big Ten, 80 for all FBS)
+    #[arg(long, value_name = "ID", requires = "ncaa")]
+    group: Option<String>,
+
+    /// Browse the preseason, regular season or postseason instead of the current one
+    #[arg(long, value_enum)]
+    season_type: Option<SeasonType>,
+
+    /// Path to the config file (defaults to ~/.config/nfl-tui/config.toml)
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Show only this game, full-screen (team abbreviation or ESPN event id)
+    #[arg(long, value_name = "ABBR or ID")]
+    game: Option<String>,
+
+    /// Print the scores once and exit instead of starting the TUI
+    #[arg(long)]
+    once: bool,
+
+    /// Print one line for a team's game every interval, for waybar/polybar.
+    /// Uses --game, or else the first favorite from config.
+    #[arg(long, conflicts_with = "once")]
+    statusbar: bool,
+
+    /// Save every scoreboard snapshot to this directory
+    #[arg(long, value_name = "DIR")]
+    record: Option<PathBuf>,
+
+    /// Play back snapshots saved with --record instead of fetching live data
+    #[arg(long, value_name = "DIR", conflicts_with = "record")]
+    replay: Option<PathBuf>,
+
+    /// Playback speed for --replay (2.0 is twice as fast)
+    #[arg(long, default_value_t = 1.0, requires = "replay")]
+    speed: f64,
+
+    /// Send requests through this proxy (HTTP_PROXY/HTTPS_PROXY are used otherwise)
+    #[arg(long, value_name = "URL")]
+    proxy: Option<String>,
+
+    /// Write debug logs to this file (filter with RUST_LOG, e.g. RUST_LOG=nfl_tui=trace)
+    #[arg(long, value_name = "PATH")]
+    log_file: Option<PathBuf>,
+
+    /// Run on made-up games instead of live data (no network needed)
+    #[arg(long, conflicts_with_all = ["replay", "record"])]
+    demo: bool,
+
+    /// Format for --once and --statusbar (json gives waybar's text/tooltip/class)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// Draw with plain ASCII instead of box drawing, Braille and emoji
+    #[arg(long)]
+    ascii: bool,
+
+    /// Start with only live games shown (`f` turns it off)
+    #[arg(long)]
+    live: bool,
+
+    /// Select this team's game once the scores load, instead of the first game
+    #[arg(long, value_name = "ABBR", conflicts_with = "game")]
+    team: Option<String>,
+
+    /// Open this week's games instead of the current week's
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    week: Option<u32>,
+
+    /// Open the games on this day, past or future
+    #[arg(long, value_name = "YYYYMMDD", value_parser = data::parse_date, conflicts_with = "week")]
+    date: Option<String>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Print a team's score with tmux style codes, for `status-right`
+    Tmux {
+        /// Team abbreviation or event id (defaults to the first favorite from config)
+        team: Option<String>,
+    },
+    /// Print a shell completion script, e.g. `nfl-tui completions zsh > ~/.zfunc/_nfl-tui`
+    #[command(hide = true)]
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+// Logs can't go to the terminal while the TUI owns it, so they only exist with --log-file
+fn init_logging(path: &std::path::Path) -> Result<(), Box<dyn Error>> {
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("nfl_tui=debug"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::sync::Mutex::new(file))
+        .with_ansi(false)
+        .init();
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
-    
+    if let Some(Command::Completions { shell }) = args.command {
+        clap_complete::generate(shell, &mut Args::command(), "nfl-tui", &mut io::stdout());
+        return Ok(());
+    }
+    // Load config before touching the terminal so errors print normally
+    let config = Config::load(args.config.clone())?;
+    let keymap = KeyMap::new(&config.keys)?;
+    if let Some(path) = &args.log_file {
+        init_logging(path)?;
+    }
+    // Likewise for problems with the record/replay directories
+    let recorder = args.record.clone().map(replay::Recorder::new).transpose()?;
+    let replay_snapshots = args.replay.as_deref().map(replay::snapshots).transpose()?;
+    let initial_league = if args.ncaa { "college-football" } else { "nfl" }.to_string();
+    let mut leagues = vec![];
+    for league in &config.leagues {
+        let slug = data::league_slug(league).ok_or_else(|| format!("Unknown league '{}' in config (nfl or ncaa)", league))?;
+        if !leagues.contains(&slug.to_string()) {
+            leagues.push(slug.to_string());
+        }
+    }
+    // The league asked for on the command line always gets a tab
+    if !leagues.is_empty() && !leagues.contains(&initial_league) {
+        leagues.insert(0, initial_league.clone());
+    }
+    let initial_query = ScoreboardQuery {
+        week: args.week,
+        dates: args.date.clone(),
+        groups: args.group.clone(),
+        season_type: args.season_type,
+    };
+    let mut client = match config.api_url() {
+        Some(url) => DataClient::with_base_url(url),
+        None => DataClient::new(),
+    };
+    if let Some(proxy) = &args.proxy {
+        client = client.with_proxy(proxy)?;
+    }
+
+    if let Some(Command::Tmux { team }) = &args.command {
+        let Some(team) = team.clone().or_else(|| config.favorites.first().cloned()) else {
+            return Err("nfl-tui tmux needs a team or a favorite team in config".into());
+        };
+        let data = client.fetch_scoreboard(&initial_league, &initial_query).await?;
+        println!("{}", headless::tmux_line(&data.events, &team));
+        return Ok(());
+    }
+
+    if args.once {
+        let json = args.output == OutputFormat::Json;
+        headless::once(&client, &initial_league, &initial_query, args.game.as_deref(), json, &mut io::stdout()).await?;
+        return Ok(());
+    }
+
+    if args.statusbar {
+        let Some(team) = args.game.clone().or_else(|| config.favorites.first().cloned()) else {
+            return Err("--statusbar needs --game or a favorite team in config".into());
+        };
+        loop {
+            // Keep showing the last score through a failed fetch
+            if let Ok(data) = client.fetch_scoreboard(&initial_league, &initial_query).await {
+                headless::StatusModule::new(&data.events, &team).print(args.output == OutputFormat::Json, &mut io::stdout())?;
+            }
+            tokio::time::sleep(Duration::from_secs(args.interval)).await;
+        }
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -155,72 +1016,274 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Create app state
     let mut app = App::new();
+    app.keymap = keymap;
+    app.interval.store(args.interval, Ordering::Relaxed);
+    app.favorites = Favorites::load(&config.favorites, Favorites::default_path());
+    *app.followed.lock().unwrap() = app.favorites.teams();
+    app.sidebar_odds = config.sidebar_odds;
+    app.show_ticker = config.ticker;
+    app.filter_live = args.live;
+    app.start_team = args.team.clone();
+    if let Some(game) = args.game.clone() {
+        app.watch = Some(game);
+        app.show_sidebar = false;
+    }
+    if let Some(secs) = config.rotate_secs {
+        app.rotate_secs = secs.max(1);
+    }
+    app.leagues = leagues;
+    app.color_support = ColorSupport::detect();
+    app.ascii = args.ascii;
+    app.logo_style = if args.ascii { LogoStyle::Braille } else { config.logo_style.resolve() };
+    app.possession_marker = config.possession_marker().to_string();
+    app.sound = config.sound.clone();
+    app.close_game = config.close_game;
+    let kickoff_reminder = config.kickoff_reminder.map(|mins| chrono::Duration::minutes(mins.into()));
+    app.kickoff_reminder = kickoff_reminder;
+
+    // Show the last known scores until the first fetch lands
+    let cache = ScoreboardCache::default_dir().map(ScoreboardCache::new);
+    // The cache only has the current week, so it's no use for another one
+    let live = !args.demo && args.replay.is_none() && args.week.is_none() && args.date.is_none();
+    if let Some((data, saved)) = cache.as_ref().filter(|_| live).and_then(|c| c.load(&initial_league)) {
+        app.events = data.events;
+        app.week = data.week.map(|w| w.number);
+        app.cached_at = Some(saved);
+    }
+    app.refilter();
+    app.select_start_team();
 
     // Setup channel for background updates
-    let (tx, mut rx) = mpsc::channel::<(Vec<GameEvent>, Option<(String, DynamicImage)>)>(100);
+    let (tx, mut rx) = mpsc::channel::<Update>(100);
+    let notifier = Arc::new(tokio::sync::Mutex::new(Notifier::new(
+        config.webhook.clone(),
+        config.ntfy.clone(),
+        config.on_event.clone(),
+        app.followed.clone(),
+        config.close_game,
+        kickoff_reminder,
+        client.http(),
+    )));
 
     // Initial fetch
-    let client = Arc::new(crate::data::DataClient::new());
+    let client = Arc::new(client);
     let client_clone = client.clone();
     let tx_clone = tx.clone();
-    let interval_secs = args.interval;
+    let interval = app.interval.clone();
     
     // Shared state for league
-    let initial_league = if args.ncaa { "college-football" } else { "nfl" }.to_string();
     let league_state = Arc::new(std::sync::Mutex::new(initial_league));
     let league_state_clone = league_state.clone();
 
+    // Shared state for the week/date being browsed
+    app.group = args.group.clone();
+    app.season_type = args.season_type;
+    let query_state = Arc::new(std::sync::Mutex::new(initial_query));
+    let query_state_clone = query_state.clone();
+
+    // Shared state for what the current view needs fetched
+    let wants_state = Arc::new(std::sync::Mutex::new(FetchWants::default()));
+    let wants_state_clone = wants_state.clone();
+
     // Channel to signal immediate refresh
     let (refresh_tx, mut refresh_rx) = tokio::sync::mpsc::channel::<()>(1);
 
-    // Spawn background data fetching task
-    tokio::spawn(async move {
-        let mut fetched_logos: std::collections::HashSet<String> = std::collections::HashSet::new();
+    // Spawn background data fetching task, or play back a recording or demo in its place
+    if args.demo {
+        tokio::spawn(demo::run(tx.clone()));
+    } else if let Some(snapshots) = replay_snapshots {
+        tokio::spawn(replay::play(snapshots, args.speed.max(0.01), tx.clone()));
+    } else {
+        // Each tab keeps its own scoreboard fresh while another league is on screen
+        for league in app.leagues.clone() {
+            let client = client.clone();
+            let tx = tx.clone();
+            let league_state = league_state.clone();
+            let interval = app.interval.clone();
+            tokio::spawn(async move {
+                loop {
+                    let showing = *league_state.lock().unwrap() == league;
+                    if !showing {
+                        match client.fetch_scoreboard(&league, &ScoreboardQuery::default()).await {
+                            Ok(data) => {
+                                let _ = tx.send(Update::LeagueScoreboard(league.clone(), data.events)).await;
+                            }
+                            // A 304 means the tab already has these scores
+                            Err(err) if err.is::<data::NotModified>() => {}
+                            Err(err) => debug!(%league, %err, "tab scoreboard fetch failed"),
+                        }
+                    }
+                    tokio::time::sleep(Duration::from_secs(interval.load(Ordering::Relaxed))).await;
+                }
+            });
+        }
+
+        tokio::spawn(async move {
+            let requested_logos: Arc<Mutex<HashSet<String>>> = Arc::default();
+            let mut failures = 0;
+            let mut last_request = None;
+            let mut last_bracket: Option<Bracket> = None;
+            let mut fetched_matchups: HashSet<String> = HashSet::new();
 
-        loop {
-            // Get current league
-            let current_league = {
-                let guard = league_state_clone.lock().unwrap();
-                guard.clone()
-            };
+            loop {
+                let interval_secs = interval.load(Ordering::Relaxed);
 
-            if let Ok(data) = client_clone.fetch_scoreboard(&current_league).await {
-                // Check for logos
-                for event in &data.events {
-                    for comp in &event.competitions {
-                        for competitor in &comp.competitors {
-                           let abbr = &competitor.team.abbreviation;
-                           if !fetched_logos.contains(abbr) {
-                               if let Some(url) = &competitor.team.logo {
-                                   if let Ok(resp) = reqwest::get(url).await {
-                                       if let Ok(bytes) = resp.bytes().await {
-                                            if let Ok(img) = image::load_from_memory(&bytes) {
-                                                 let _ = tx_clone.send((Vec::new(), Some((abbr.clone(), img)))).await;
-                                                 fetched_logos.insert(abbr.clone());
-                                            }
-                                       }
-                                   }
-                               }
-                           }
+                // Get current league
+                let current_league = {
+                    let guard = league_state_clone.lock().unwrap();
+                    guard.clone()
+                };
+
+                let current_query = {
+                    let guard = query_state_clone.lock().unwrap();
+                    guard.clone()
+                };
+
+                // The UI clears its data when the league or week changes, so a 304 would leave it empty
+                let request = Some((current_league.clone(), current_query.clone()));
+                if request != last_request {
+                    // Team ids mean different teams in each league
+                    if last_request.as_ref().is_some_and(|(league, _)| *league != current_league) {
+                        fetched_matchups.clear();
+                    }
+                    client_clone.reset_validators();
+                    last_request = request;
+                }
+
+                let result = client_clone.fetch_scoreboard(&current_league, &current_query).await;
+                match &result {
+                    // Unchanged since last time; the UI already has these scores
+                    Err(err) if err.is::<data::NotModified>() => {
+                        debug!(league = %current_league, "scoreboard unchanged");
+                        failures = 0;
+                    }
+                    Err(err) if err.is::<data::BadResponse>() => {
+                        warn!(league = %current_league, %err, "scoreboard didn't parse");
+                        let _ = tx_clone.send(Update::BadResponse(BadResponseNotice { endpoint: "scoreboard", message: err.to_string() })).await;
+                    }
+                    Err(err) => {
+                        failures += 1;
+                        let retry_in = data::backoff(failures, interval_secs);
+                        warn!(league = %current_league, failures, retry_in_secs = retry_in.as_secs(), %err, "scoreboard fetch failed");
+                        let _ = tx_clone.send(Update::FetchError(FetchError { message: err.to_string(), retry_in })).await;
+                    }
+                    Ok(data) => {
+                        info!(league = %current_league, query = ?current_query, events = data.events.len(), "scoreboard fetched");
+                        failures = 0;
+                    }
+                }
+
+                if let Ok(data) = result {
+                    if let Some(recorder) = &recorder {
+                        if let Err(err) = recorder.save(&data) {
+                            warn!(%err, "couldn't record scoreboard");
+                        }
+                    }
+                    // Only the default slate is worth showing at the next startup
+                    if let Some(cache) = cache.as_ref().filter(|_| current_query == ScoreboardQuery::default()) {
+                        if let Err(err) = cache.save(&current_league, &data) {
+                            warn!(%err, "couldn't cache scoreboard");
+                        }
+                    }
+                    // Logos download in the background so they don't hold up the scores
+                    let missing: Vec<(String, String)> = {
+                        let mut requested = requested_logos.lock().unwrap();
+                        data.events
+                            .iter()
+                            .flat_map(|event| &event.competitions)
+                            .flat_map(|comp| &comp.competitors)
+                            .filter_map(|c| Some((c.team.abbreviation.clone(), c.team.logo.clone()?)))
+                            .filter(|(abbr, _)| requested.insert(abbr.clone()))
+                            .collect()
+                    };
+                    if !missing.is_empty() {
+                        tokio::spawn(fetch_logos(client_clone.clone(), tx_clone.clone(), missing, requested_logos.clone()));
+                    }
+
+                    // Alerts go out after the UI has the new scores, and off this loop, since
+                    // spelling out scores waits on game summaries
+                    let events = data.events.clone();
+                    let _ = tx_clone.send(Update::Scoreboard(data)).await;
+                    let showing = wants_state_clone.lock().unwrap().summary_event.clone();
+                    let (notifier, source, tx, league) = (notifier.clone(), client_clone.clone(), tx_clone.clone(), current_league.clone());
+                    tokio::spawn(async move {
+                        // The lock keeps each refresh's alerts in order
+                        let mut notifier = notifier.lock().await;
+                        for (event_id, summary, detail) in notifier.observe(&source, &league, &events, showing.as_deref()).await {
+                            let _ = tx.send(Update::Summary(event_id.clone(), Box::new(summary))).await;
+                            if let Some(detail) = detail {
+                                let _ = tx.send(Update::Scored(event_id, detail)).await;
+                            }
+                        }
+                    });
+                }
+
+                let wants = {
+                    let guard = wants_state_clone.lock().unwrap();
+                    guard.clone()
+                };
+                if let Some(event_id) = wants.summary_event {
+                    debug!(%event_id, "fetching summary");
+                    if let Some(summary) = surface_bad_response(client_clone.fetch_summary(&current_league, &event_id).await, "summary", &tx_clone).await {
+                        let _ = tx_clone.send(Update::Summary(event_id, Box::new(summary))).await;
+                    }
+                }
+                if wants.news {
+                    if let Some(news) = surface_bad_response(client_clone.fetch_news(&current_league).await, "news", &tx_clone).await {
+                        let _ = tx_clone.send(Update::News(news)).await;
+                    }
+                }
+                if wants.standings {
+                    if let Some(standings) = surface_bad_response(client_clone.fetch_standings(&current_league).await, "standings", &tx_clone).await {
+                        let _ = tx_clone.send(Update::Standings(standings)).await;
+                    }
+                }
+                if wants.rankings {
+                    if let Some(rankings) = surface_bad_response(client_clone.fetch_rankings(&current_league).await, "rankings", &tx_clone).await {
+                        let _ = tx_clone.send(Update::Rankings(rankings)).await;
+                    }
+                }
+                if wants.bracket {
+                    if let Ok(bracket) = data::fetch_bracket(client_clone.as_ref(), &current_league, last_bracket.as_ref()).await {
+                        if last_bracket.as_ref() != Some(&bracket) {
+                            let _ = tx_clone.send(Update::Bracket(bracket.clone())).await;
+                            last_bracket = Some(bracket);
+                        }
+                    }
+                }
+                // A 304 here just means the bowls the UI has are still current
+                if wants.bowls {
+                    if let Some(data) = surface_bad_response(client_clone.fetch_scoreboard(&current_league, &data::bowls_query()).await, "bowls", &tx_clone).await {
+                        let _ = tx_clone.send(Update::Bowls(data.events)).await;
+                    }
+                }
+                if let Some((team_id, opponent_id, season)) = wants.head_to_head {
+                    let key = data::matchup_key(&team_id, &opponent_id);
+                    if !fetched_matchups.contains(&key) {
+                        match data::fetch_head_to_head(client_clone.as_ref(), &current_league, &team_id, &opponent_id, season).await {
+                            Ok(meetings) => {
+                                fetched_matchups.insert(key.clone());
+                                let _ = tx_clone.send(Update::HeadToHead(key, meetings)).await;
+                            }
+                            Err(err) => warn!(%err, "head-to-head fetch failed"),
                         }
                     }
                 }
-                
-                let _ = tx_clone.send((data.events, None)).await;
-            }
             
-            // scalar ref to interval for sleep
-            tokio::select! {
-                _ = tokio::time::sleep(Duration::from_secs(interval_secs)) => {},
-                _ = refresh_rx.recv() => {
-                    // Woke up early to refresh!
+                // Retry sooner (then gradually later) while the API is failing
+                let delay = if failures > 0 { data::backoff(failures, interval_secs) } else { Duration::from_secs(interval_secs) };
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {},
+                    _ = refresh_rx.recv() => {
+                        // Woke up early to refresh!
+                    }
                 }
             }
-        }
-    });
+        });
+    }
 
     // Run app loop
-    let res = run_app(&mut terminal, &mut app, &mut rx, league_state, refresh_tx).await;
+    let res = run_app(&mut terminal, &mut app, &mut rx, league_state, query_state, wants_state, refresh_tx).await;
 
     // Restore terminal
     disable_raw_mode()?;
@@ -238,13 +1301,142 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-async fn run_app<B: Backend>(
+// Week numbers and conferences don't carry over between leagues
+fn switch_league(app: &mut App, league: &str, league_state: &Mutex<String>, query_state: &Mutex<ScoreboardQuery>, refresh_tx: &mpsc::Sender<()>) {
+    // Keep the slate being left for its tab, unless it's another week's
+    let previous = std::mem::replace(&mut *league_state.lock().unwrap(), league.to_string());
+    let query = std::mem::take(&mut *query_state.lock().unwrap());
+    if query == ScoreboardQuery::default() && !app.events.is_empty() {
+        app.league_boards.insert(previous, app.events.clone());
+    }
+    app.week = None;
+    app.group = None;
+    app.season_type = None;
+    // Clear events to avoid confusion while loading
+    app.events.clear();
+    app.summaries.clear();
+    app.standings = None;
+    app.news = None;
+    app.rankings = None;
+    app.bracket = None;
+    app.bowls = None;
+    app.head_to_head.clear();
+    if matches!(app.view, View::Rankings | View::Bracket | View::Bowls) {
+        app.view = View::Game;
+    }
+    app.state.select(None);
+    // Show the tab's last known scores until the fetch lands
+    if let Some(events) = app.league_boards.get(league) {
+        app.events = events.clone();
+        app.state.select(Some(0));
+    }
+    app.refilter();
+    let _ = refresh_tx.try_send(());
+}
+
+// Moves `step` tabs along, wrapping around
+fn cycle_league(app: &mut App, step: isize, league_state: &Mutex<String>, query_state: &Mutex<ScoreboardQuery>, refresh_tx: &mpsc::Sender<()>) {
+    let count = app.leagues.len() as isize;
+    if count < 2 {
+        return;
+    }
+    let current = app.leagues.iter().position(|l| *l == app.league_label).unwrap_or(0) as isize;
+    let next = app.leagues[(current + step).rem_euclid(count) as usize].clone();
+    switch_league(app, &next, league_state, query_state, refresh_tx);
+}
+
+fn go_to_week(app: &mut App, week: u32, query_state: &Mutex<ScoreboardQuery>, refresh_tx: &mpsc::Sender<()>) {
+    {
+        // A week replaces a `--date`
+        let mut query = query_state.lock().unwrap();
+        query.week = Some(week);
+        query.dates = None;
+    }
+    app.events.clear();
+    app.refilter();
+    app.state.select(Some(0));
+    let _ = refresh_tx.try_send(());
+}
+
+fn run_command(app: &mut App, command: command::Command, league_state: &Mutex<String>, query_state: &Mutex<ScoreboardQuery>, refresh_tx: &mpsc::Sender<()>) {
+    match command {
+        command::Command::Quit => app.should_quit = true,
+        command::Command::League(league) => {
+            if *league_state.lock().unwrap() != league {
+                switch_league(app, &league, league_state, query_state, refresh_tx);
+            }
+        }
+        command::Command::Week(week) => go_to_week(app, week, query_state, refresh_tx),
+        command::Command::Interval(secs) => {
+            app.interval.store(secs, Ordering::Relaxed);
+            // Wake the fetch task so the new interval starts now
+            let _ = refresh_tx.try_send(());
+            app.toast(format!("Refreshing every {}s", secs), Color::Cyan);
+        }
+        command::Command::Filter(live) => {
+            if app.filter_live != live {
+                app.toggle_live_filter();
+            }
+        }
+    }
+}
+
+// The views' own fetches fail quietly and are retried next refresh, but data that
+// doesn't parse won't fix itself, so that goes on screen like a scoreboard failure
+async fn surface_bad_response<T>(result: anyhow::Result<T>, endpoint: &'static str, tx: &mpsc::Sender<Update>) -> Option<T> {
+    match result {
+        Ok(data) => Some(data),
+        Err(err) if err.is::<data::BadResponse>() => {
+            let _ = tx.send(Update::BadResponse(BadResponseNotice { endpoint, message: err.to_string() })).await;
+            None
+        }
+        Err(_) => None,
+    }
+}
+
+/// Downloads and decodes logos, a few at a time. Teams whose logo fails are taken
+/// back out of `requested` so the next refresh tries them again.
+async fn fetch_logos(client: Arc<DataClient>, tx: mpsc::Sender<Update>, logos: Vec<(String, String)>, requested: Arc<Mutex<HashSet<String>>>) {
+    let mut logos = logos.into_iter();
+    let mut tasks = JoinSet::new();
+    loop {
+        while tasks.len() < LOGO_DOWNLOADS {
+            let Some((abbr, url)) = logos.next() else { break };
+            let client = client.clone();
+            tasks.spawn(async move {
+                let result = async {
+                    let bytes = client.fetch_bytes(&url).await?;
+                    anyhow::Ok(tokio::task::spawn_blocking(move || logo::decode(&bytes)).await??)
+                }
+                .await;
+                (abbr, url, result)
+            });
+        }
+        let Some(joined) = tasks.join_next().await else { break };
+        match joined {
+            Ok((abbr, _, Ok(img))) => {
+                debug!(team = %abbr, width = img.width(), height = img.height(), "logo loaded");
+                let _ = tx.send(Update::Logo(abbr, img)).await;
+            }
+            Ok((abbr, url, Err(err))) => {
+                warn!(team = %abbr, %url, %err, "logo download failed");
+                requested.lock().unwrap().remove(&abbr);
+            }
+            Err(err) => warn!(%err, "logo task failed"),
+        }
+    }
+}
+
+async fn run_app<B: Backend + Write>(
     terminal: &mut Terminal<B>,
     app: &mut App,
-    rx: &mut mpsc::Receiver<(Vec<GameEvent>, Option<(String, DynamicImage)>)>,
+    rx: &mut mpsc::Receiver<Update>,
     league_state: Arc<std::sync::Mutex<String>>,
+    query_state: Arc<std::sync::Mutex<ScoreboardQuery>>,
+    wants_state: Arc<std::sync::Mutex<FetchWants>>,
     refresh_tx: tokio::sync::mpsc::Sender<()>,
 ) -> io::Result<()> {
+    let mut last_draw: Option<Instant> = None;
     loop {
         // Pass current league name to UI for display (hacky, or add field to App?)
         // Let's add a temporary field or just assume app doesn't know yet.
@@ -256,44 +1448,381 @@ async fn run_app<B: Backend>(
              app.league_label = g.clone();
         }
 
-        terminal.draw(|f| ui(f, app))?;
+        // Tell the fetch task what the current view needs; fetch right away if it's missing
+        {
+            let wanted = FetchWants {
+                summary_event: if app.view.needs_summary() {
+                    app.selected_event().map(|e| e.id.clone())
+                } else {
+                    None
+                },
+                standings: app.view == View::Standings,
+                news: app.view == View::News,
+                rankings: app.view == View::Rankings,
+                bracket: app.view == View::Bracket,
+                bowls: app.view == View::Bowls,
+                head_to_head: if app.view == View::HeadToHead {
+                    app.selected_event().and_then(|game| {
+                        let comp = game.competitions.first()?;
+                        let team = comp.competitors.first()?.team.id.clone()?;
+                        let opponent = comp.competitors.get(1)?.team.id.clone()?;
+                        Some((team, opponent, game.season.as_ref()?.year))
+                    })
+                } else {
+                    None
+                },
+            };
+            let mut guard = wants_state.lock().unwrap();
+            if *guard != wanted {
+                let needs_fetch = wanted.summary_event.as_ref().is_some_and(|id| !app.summaries.contains_key(id))
+                    || (wanted.standings && app.standings.is_none())
+                    || (wanted.news && app.news.is_none())
+                    || (wanted.rankings && app.rankings.is_none())
+                    || (wanted.bracket && app.bracket.is_none())
+                    || (wanted.bowls && app.bowls.is_none())
+                    || wanted.head_to_head.as_ref().is_some_and(|(team, opponent, _)| !app.head_to_head.contains_key(&data::matchup_key(team, opponent)));
+                *guard = wanted;
+                if needs_fetch {
+                    let _ = refresh_tx.try_send(());
+                }
+            }
+        }
+
+        app.tick_rotate();
+        app.expire_toasts();
+        let idle = app.idle();
+        if app.dirty || (!idle && app.animating()) || last_draw.is_none_or(|at| at.elapsed() >= CLOCK_TICK) {
+            terminal.draw(|f| {
+                ui(f, app);
+                app.color_support.adapt_buffer(f.buffer_mut());
+                if app.ascii {
+                    glyphs::to_ascii(f.buffer_mut());
+                }
+            })?;
+            place_logos(terminal, app)?;
+            app.dirty = false;
+            last_draw = Some(Instant::now());
+        }
 
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => app.should_quit = true,
-                    KeyCode::Char('l') => app.show_logos = !app.show_logos,
-                    KeyCode::Char('f') => app.toggle_live_filter(), 
-                    KeyCode::Char('b') => app.show_sidebar = !app.show_sidebar,
-                    KeyCode::Char('c') => {
-                        // Toggle League
+        if event::poll(if idle { IDLE_POLL } else { TICK })? {
+            let event = event::read()?;
+            app.dirty = true;
+            if matches!(event, Event::Key(_) | Event::Mouse(_)) {
+                app.last_input = Instant::now();
+            }
+            if let Event::Mouse(mouse) = event {
+                if !app.show_help {
+                    app.on_mouse(mouse);
+                }
+            }
+            if let Event::Key(key) = event {
+                // The conference picker is modal and takes raw keys
+                if let Some(picker) = app.group_picker.as_mut() {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    // The first entry is "Top 25", i.e. no group
+                    let count = data::NCAA_GROUPS.len() + 1;
+                    let selected = picker.selected().unwrap_or(0);
+                    match key.code {
+                        KeyCode::Down | KeyCode::Char('j') => picker.select(Some((selected + 1) % count)),
+                        KeyCode::Up | KeyCode::Char('k') => picker.select(Some((selected + count - 1) % count)),
+                        KeyCode::Esc | KeyCode::Char('q') => app.group_picker = None,
+                        KeyCode::Enter => {
+                            let group = selected.checked_sub(1).map(|i| data::NCAA_GROUPS[i].0.to_string());
+                            app.group_picker = None;
+                            if group != app.group {
+                                query_state.lock().unwrap().groups = group.clone();
+                                app.group = group;
+                                app.events.clear();
+                                app.refilter();
+                                app.state.select(Some(0));
+                                let _ = refresh_tx.try_send(());
+                            }
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                // So is the search prompt, until Enter keeps the results or Esc drops them
+                if app.searching {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    let search = app.search.get_or_insert_with(String::new);
+                    match key.code {
+                        KeyCode::Char(c) => search.push(c),
+                        KeyCode::Backspace => {
+                            search.pop();
+                        }
+                        KeyCode::Enter => {
+                            app.searching = false;
+                            if search.is_empty() {
+                                app.search = None;
+                            }
+                        }
+                        KeyCode::Esc => {
+                            app.searching = false;
+                            app.search = None;
+                        }
+                        _ => {}
+                    }
+                    app.refilter();
+                    app.state.select(Some(0));
+                    continue;
+                }
+
+                // Jumps as soon as the letters name exactly one team, or on Enter to the first that fits
+                if let Some(typed) = app.jump.as_mut() {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char(c) => typed.push(c.to_ascii_uppercase()),
+                        KeyCode::Backspace => {
+                            typed.pop();
+                        }
+                        KeyCode::Esc => app.jump = None,
+                        _ => {}
+                    }
+                    let Some(typed) = app.jump.clone() else { continue };
+                    let abbreviations: Vec<&str> = app
+                        .events
+                        .iter()
+                        .flat_map(|e| &e.competitions)
+                        .flat_map(|c| &c.competitors)
+                        .map(|c| c.team.abbreviation.as_str())
+                        .filter(|a| !typed.is_empty() && a.starts_with(typed.as_str()))
+                        .collect();
+                    let target = match (key.code, abbreviations.as_slice()) {
+                        (KeyCode::Enter, [first, ..]) => Some(first.to_string()),
+                        (_, [only]) if *only == typed => Some(typed.clone()),
+                        _ => None,
+                    };
+                    if let Some(target) = target {
+                        app.jump = None;
+                        app.jump_to_team(&target);
+                    } else if key.code == KeyCode::Enter {
+                        app.jump = None;
+                        app.toast(format!("No game for {}", typed), Color::Yellow);
+                    }
+                    continue;
+                }
+
+                // And the command line, which runs its command on Enter
+                if let Some(typed) = app.command.as_mut() {
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match key.code {
+                        KeyCode::Char(c) => typed.push(c),
+                        KeyCode::Backspace => {
+                            typed.pop();
+                        }
+                        KeyCode::Esc => app.command = None,
+                        KeyCode::Enter => {
+                            let input = app.command.take().unwrap_or_default();
+                            match command::Command::parse(&input) {
+                                Ok(command) => run_command(app, command, &league_state, &query_state, &refresh_tx),
+                                Err(err) => app.toast(err.to_string(), Color::Red),
+                            }
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                let action = app.keymap.handle(key);
+                if let Some(action) = action {
+                    debug!(?action, show_help = app.show_help, "key action");
+                }
+                // The help overlay is modal: only closing it (or quitting) gets through
+                let action = if app.show_help {
+                    action.filter(|a| matches!(a, Action::Quit | Action::ToggleHelp | Action::Dismiss))
+                } else {
+                    action
+                };
+                match action {
+                    Some(Action::Quit) => app.should_quit = true,
+                    Some(Action::ToggleHelp) => app.show_help = !app.show_help,
+                    // Esc also clears a search that's been kept
+                    Some(Action::Dismiss) if !app.show_help && app.search.is_some() => {
+                        app.search = None;
+                        app.refilter();
+                        app.state.select(Some(0));
+                    }
+                    Some(Action::Dismiss) => app.show_help = false,
+                    Some(Action::Search) if app.watch.is_none() => {
+                        app.searching = true;
+                        app.search.get_or_insert_with(String::new);
+                        app.state.select(Some(0));
+                    }
+                    Some(Action::Search) => {}
+                    Some(Action::JumpToTeam) if app.watch.is_none() => app.jump = Some(String::new()),
+                    Some(Action::JumpToTeam) => {}
+                    Some(Action::Command) => app.command = Some(String::new()),
+                    Some(Action::ToggleFavorite) => app.cycle_favorite(),
+                    Some(Action::CycleSort) => app.cycle_sort(),
+                    Some(Action::ToggleLogos) => app.show_logos = !app.show_logos,
+                    Some(Action::ToggleLiveFilter) => app.toggle_live_filter(),
+                    // There's nothing to pick from when watching a single game
+                    Some(Action::ToggleSidebar) if app.watch.is_none() => app.show_sidebar = !app.show_sidebar,
+                    Some(Action::ToggleSidebar) => {}
+                    Some(Action::ToggleFullscreen) => app.fullscreen = !app.fullscreen,
+                    Some(Action::ToggleTicker) => app.show_ticker = !app.show_ticker,
+                    Some(Action::ToggleMute) => {
+                        app.muted = !app.muted;
+                        let text = if app.muted { "Sound alerts muted" } else { "Sound alerts on" };
+                        app.toast(text.to_string(), Color::Cyan);
+                    }
+                    Some(Action::ToggleRotate) => app.toggle_rotate(),
+                    Some(Action::ToggleRedZone) => app.redzone = !app.redzone,
+                    // Conferences only exist in college football
+                    Some(Action::PickGroup) if app.league_label == "college-football" => {
+                        let current = app.group.as_ref().and_then(|g| data::NCAA_GROUPS.iter().position(|(id, _)| id == g));
+                        app.group_picker = Some(ListState::default().with_selected(Some(current.map_or(0, |i| i + 1))));
+                    }
+                    Some(Action::PickGroup) => {}
+                    Some(Action::TogglePlays) => app.toggle_view(View::Plays),
+                    Some(Action::ToggleBoxScore) => app.toggle_view(View::BoxScore),
+                    Some(Action::ToggleStandings) => app.toggle_view(View::Standings),
+                    Some(Action::ToggleNews) => app.toggle_view(View::News),
+                    // The NFL has no polls
+                    Some(Action::ToggleRankings) if app.league_label == "college-football" => app.toggle_view(View::Rankings),
+                    Some(Action::ToggleRankings) => {}
+                    Some(Action::ToggleBracket) if app.league_label == "college-football" => app.toggle_view(View::Bowls),
+                    Some(Action::ToggleBracket) => app.toggle_view(View::Bracket),
+                    Some(Action::ToggleHeadToHead) => app.toggle_view(View::HeadToHead),
+                    Some(Action::ScrollDown) => app.detail_scroll = app.detail_scroll.saturating_add(5),
+                    Some(Action::ScrollUp) => app.detail_scroll = app.detail_scroll.saturating_sub(5),
+                    Some(Action::SwitchLeague) => {
+                        let other = if *league_state.lock().unwrap() == "nfl" { "college-football" } else { "nfl" };
+                        switch_league(app, other, &league_state, &query_state, &refresh_tx);
+                    }
+                    Some(Action::ToggleSplit) if app.split.is_some() => app.split = None,
+                    Some(Action::ToggleSplit) => {
+                        if let Some(game) = app.selected_event() {
+                            let (id, name) = (game.id.clone(), game.short_name.clone());
+                            app.split = Some(id);
+                            app.view = View::Game;
+                            app.toast(format!("Pinned {}; pick a game to watch beside it", name), Color::Cyan);
+                        }
+                    }
+                    Some(Action::NextLeague) => cycle_league(app, 1, &league_state, &query_state, &refresh_tx),
+                    Some(Action::PreviousLeague) => cycle_league(app, -1, &league_state, &query_state, &refresh_tx),
+                    Some(action @ (Action::PrevWeek | Action::NextWeek)) => {
+                        if let Some(week) = app.week {
+                            let target = if action == Action::NextWeek { week + 1 } else { week.saturating_sub(1).max(1) };
+                            go_to_week(app, target, &query_state, &refresh_tx);
+                        }
+                    }
+                    // Week numbers restart in each part of the season, so start from ESPN's default week
+                    Some(Action::CycleSeasonType) => {
+                        app.season_type = SeasonType::cycle(app.season_type);
                         {
-                            let mut guard = league_state.lock().unwrap();
-                            if *guard == "nfl" {
-                                *guard = "college-football".to_string();
-                            } else {
-                                *guard = "nfl".to_string();
-                            }
+                            let mut query = query_state.lock().unwrap();
+                            query.season_type = app.season_type;
+                            query.week = None;
+                            query.dates = None;
                         }
-                        // Clear events to avoid confusion while loading
+                        app.week = None;
                         app.events.clear();
-                        app.state.select(None);
-                        // Signal refresh
+                        app.refilter();
+                        app.state.select(Some(0));
                         let _ = refresh_tx.try_send(());
                     }
-                    KeyCode::Down | KeyCode::Char('j') => app.next(),
-                    KeyCode::Up | KeyCode::Char('k') => app.previous(),
-                    _ => {}
+                    // Picking a game by hand takes over from auto-rotate
+                    Some(Action::Next) => {
+                        app.next();
+                        app.detail_scroll = 0;
+                        app.rotate = false;
+                    }
+                    Some(Action::Previous) => {
+                        app.previous();
+                        app.detail_scroll = 0;
+                        app.rotate = false;
+                    }
+                    None => {}
                 }
             }
         }
 
-        while let Ok((events, logo_update)) = rx.try_recv() {
-            if !events.is_empty() {
-                app.events = events;
-            }
-            if let Some((abbr, img)) = logo_update {
-                app.logos.insert(abbr, img);
+        while let Ok(update) = rx.try_recv() {
+            app.dirty = true;
+            match update {
+                Update::FetchError(err) => {
+                    debug!(message = %err.message, "showing fetch error");
+                    app.toast(format!("Fetch failed: {}", err.message), Color::Red);
+                    app.fetch_error = Some((err, Instant::now()));
+                }
+                Update::BadResponse(notice) => {
+                    // Toast once, not on every refresh it's still bad
+                    if app.bad_response.as_ref().is_none_or(|(shown, _)| *shown != notice) {
+                        app.toast(format!("Couldn't read {}: {}", notice.endpoint, notice.message), Color::Magenta);
+                    }
+                    app.bad_response = Some((notice, Instant::now()));
+                }
+                Update::Scoreboard(data) => {
+                    debug!(events = data.events.len(), "applying scoreboard");
+                    app.fetch_error = None;
+                    if app.bad_response.as_ref().is_some_and(|(notice, _)| notice.endpoint == "scoreboard") {
+                        app.bad_response = None;
+                    }
+                    // Diffing against a stale cached board would alert on everything since it was saved
+                    let from_cache = app.cached_at.take().is_some();
+                    if !data.events.is_empty() {
+                        // New scores can reorder the list; follow the selected game
+                        let selected = app.selected_event().map(|e| e.id.clone());
+                        let previous = std::mem::replace(&mut app.events, data.events);
+                        app.refilter();
+                        app.refreshed_at = Some(Instant::now());
+                        if let Some(id) = selected {
+                            app.select_event(&id);
+                        }
+                        app.select_start_team();
+                        app.check_red_zone();
+                        if !from_cache {
+                            app.check_scrimmage(&previous);
+                            app.check_alerts(previous);
+                        }
+                    }
+                    app.week = data.week.map(|w| w.number);
+                }
+                Update::Logo(abbr, img) => {
+                    app.logo_payloads.remove(&abbr);
+                    app.logo_points.get_mut().retain(|(team, _, _), _| *team != abbr);
+                    app.logos.insert(abbr, img);
+                }
+                Update::Summary(event_id, summary) => {
+                    app.summaries.insert(event_id, *summary);
+                }
+                Update::Scored(event_id, detail) => {
+                    app.fill_in_score(&event_id, detail);
+                }
+                Update::Standings(standings) => {
+                    app.standings = Some(standings);
+                }
+                Update::News(news) => {
+                    app.news = Some(news);
+                }
+                Update::Rankings(rankings) => {
+                    app.rankings = Some(rankings);
+                }
+                Update::Bracket(bracket) => {
+                    app.bracket = Some(bracket);
+                }
+                Update::Bowls(bowls) => {
+                    app.bowls = Some(bowls);
+                }
+                Update::HeadToHead(key, meetings) => {
+                    app.head_to_head.insert(key, meetings);
+                }
+                Update::LeagueScoreboard(league, events) => {
+                    if league != app.league_label {
+                        app.league_boards.insert(league, events);
+                    }
+                }
             }
         }
 
@@ -305,8 +1834,54 @@ async fn run_app<B: Backend>(
 
 fn ui(f: &mut Frame, app: &mut App) {
     let size = f.area();
-    
-    let constraints = if app.show_sidebar {
+    let mut body = size;
+    app.compact = size.width < COMPACT_WIDTH || size.height < COMPACT_HEIGHT;
+
+    // An open prompt takes the bottom line even in fullscreen
+    let prompt = if app.searching {
+        Some(prompt_line("/", app.search.as_deref().unwrap_or_default(), "Enter to keep, Esc to clear"))
+    } else if let Some(typed) = &app.command {
+        Some(prompt_line(":", typed, "league nfl|ncaa, week N, interval SECS, filter live|all, quit"))
+    } else {
+        app.jump
+            .as_deref()
+            .map(|typed| prompt_line("Team: ", typed, "type an abbreviation, Enter for the first match, Esc to cancel"))
+    };
+    if prompt.is_some() || !app.fullscreen {
+        let rows = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(size);
+        body = rows[0];
+        match prompt {
+            Some(prompt) => f.render_widget(Paragraph::new(prompt), rows[1]),
+            None => {
+                let hints = views::hints::HintBar {
+                    keymap: &app.keymap,
+                    hints: app.view.hints(),
+                    updated: app.refreshed_at.map(|at| format!("Updated {}s ago", at.elapsed().as_secs())),
+                };
+                f.render_widget(hints, rows[1]);
+            }
+        }
+    }
+
+    if app.leagues.len() > 1 && !app.fullscreen {
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(body);
+        body = rows[1];
+        draw_league_tabs(f, app, rows[0]);
+    }
+
+    if app.show_ticker && !app.fullscreen {
+        let rows = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).split(body);
+        body = rows[0];
+        let selected = app.selected_event().map(|e| e.id.clone());
+        let ticker = views::ticker::Ticker {
+            events: app.events.iter().filter(|e| Some(&e.id) != selected.as_ref()).collect(),
+            elapsed_ms: app.started.elapsed().as_millis(),
+        };
+        f.render_widget(ticker, rows[1]);
+    }
+
+    let show_sidebar = app.show_sidebar && !app.fullscreen;
+    let constraints = if show_sidebar {
         vec![Constraint::Percentage(25), Constraint::Percentage(75)]
     } else {
         vec![Constraint::Min(0)]
@@ -315,9 +1890,10 @@ fn ui(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(constraints)
-        .split(size);
+        .split(body);
 
-    if app.show_sidebar {
+    app.sidebar_area = show_sidebar.then(|| chunks[0]);
+    if show_sidebar {
         draw_sidebar(f, app, chunks[0]);
         // Safe because constraints enusres we have 2 chunks if show_sidebar is true
         if chunks.len() > 1 {
@@ -326,28 +1902,159 @@ fn ui(f: &mut Frame, app: &mut App) {
     } else {
         draw_main_panel(f, app, chunks[0]);
     }
+
+    if let Some((err, at)) = &app.fetch_error {
+        let remaining = err.retry_in.saturating_sub(at.elapsed()).as_secs();
+        let banner = Paragraph::new(format!(" API unreachable, retrying in {}s ({}) ", remaining, err.message))
+            .style(Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+        f.render_widget(banner, Rect { height: 1, ..body });
+    } else if let Some((notice, _)) = app.bad_response.as_ref().filter(|(_, at)| at.elapsed().as_secs() < 2 * app.interval.load(Ordering::Relaxed)) {
+        let banner = Paragraph::new(format!(" Couldn't read the {} response: {} ", notice.endpoint, notice.message))
+            .style(Style::default().bg(Color::Magenta).fg(Color::White).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+        f.render_widget(banner, Rect { height: 1, ..body });
+    } else if let Some(saved) = app.cached_at {
+        let minutes = saved.elapsed().map(|d| d.as_secs() / 60).unwrap_or(0);
+        let banner = Paragraph::new(format!(" Cached scores from {}m ago, waiting for live data ", minutes))
+            .style(Style::default().bg(Color::Yellow).fg(Color::Black).add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+        f.render_widget(banner, Rect { height: 1, ..body });
+    }
+
+    views::toasts::draw_toasts(f, app.toasts.iter().map(|(toast, _)| toast), body);
+
+    if let Some(picker) = app.group_picker.as_mut() {
+        let items: Vec<&str> = std::iter::once("Top 25").chain(data::NCAA_GROUPS.iter().map(|(_, name)| *name)).collect();
+        views::picker::draw_picker(f, "CONFERENCE", &items, picker, size);
+    }
+
+    if app.show_help {
+        let on_off = |b: bool| if b { "on" } else { "off" }.to_string();
+        let help = views::help::HelpOverlay {
+            keymap: &app.keymap,
+            settings: vec![
+                ("League", app.league_label.clone()),
+                ("Week", app.week.map(|w| w.to_string()).unwrap_or_else(|| "current".to_string())),
+                ("Season", app.season_type.map_or("current", |t| t.label()).to_string()),
+                ("Conference", match &app.group {
+                    _ if app.league_label != "college-football" => "n/a".to_string(),
+                    Some(group) => data::group_name(group).to_string(),
+                    None => "Top 25".to_string(),
+                }),
+                ("Refresh", format!("every {}s", app.interval.load(Ordering::Relaxed))),
+                ("Live only", on_off(app.filter_live)),
+                ("Sort", app.sort_mode.label().to_string()),
+                ("Logos", on_off(app.show_logos)),
+                ("Sidebar", on_off(app.show_sidebar)),
+                ("Fullscreen", on_off(app.fullscreen)),
+                ("Colors", app.color_support.label().to_string()),
+                ("ASCII", on_off(app.ascii)),
+                ("Ticker", on_off(app.show_ticker)),
+                ("RedZone", on_off(app.redzone)),
+                ("Auto-rotate", if app.rotate { format!("every {}s", app.rotate_secs) } else { "off".to_string() }),
+            ],
+        };
+        f.render_widget(help, size);
+    }
+}
+
+// "NFL · 3 live │ NCAA · 12 live", with the league on screen highlighted
+fn draw_league_tabs(f: &mut Frame, app: &App, area: Rect) {
+    let titles = app.leagues.iter().map(|league| {
+        let events = if *league == app.league_label { Some(&app.events) } else { app.league_boards.get(league) };
+        let live = events.map_or(0, |events| events.iter().filter(|e| e.status.type_field.state == GameState::In).count());
+        match live {
+            0 => Line::from(format!(" {} ", data::league_name(league))),
+            n => Line::from(vec![
+                Span::raw(format!(" {} ", data::league_name(league))),
+                Span::styled(format!("· {} live ", n), Style::default().fg(Color::Green)),
+            ]),
+        }
+    });
+    let selected = app.leagues.iter().position(|l| *l == app.league_label);
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD).bg(Color::DarkGray).fg(Color::White));
+    f.render_widget(tabs, area);
+}
+
+// A one-line text prompt with a cursor, for the bottom bar
+fn prompt_line<'a>(label: &'a str, text: &'a str, help: &'a str) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(label, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        Span::raw(text),
+        Span::styled("█", Style::default().fg(Color::Gray)),
+        Span::styled(format!("   {}", help), Style::default().fg(Color::DarkGray)),
+    ])
 }
 
 fn draw_sidebar(f: &mut Frame, app: &mut App, area: Rect) {
-    let events = app.filtered_events();
-    let items: Vec<ListItem> = events
-        .iter()
+    let items: Vec<ListItem> = app
+        .filtered_events()
         .map(|game| {
-            let title = &game.short_name;
-            let status = if game.status.type_field.state == "pre" {
+            let title = match season_label(game, &app.league_label) {
+                Some(label) => format!("{} · {}", label, sidebar_title(game)),
+                None => sidebar_title(game),
+            };
+            let status = if game.status.type_field.state == GameState::Pre {
                  "Pre".to_string()
-            } else if game.status.type_field.state == "post" {
-                 "Final".to_string()
+            } else if game.status.type_field.state == GameState::Post {
+                 if game.status.is_overtime() { "Final/OT".to_string() } else { "Final".to_string() }
+            } else if is_red_zone(game) {
+                 format!("{} · RZ", game_clock(game, app.refreshed_at))
+            } else if game.status.is_overtime() {
+                 format!("{} · {}", game_clock(game, app.refreshed_at), game.status.period_label())
             } else {
-                 game.status.display_clock.clone()
+                 game_clock(game, app.refreshed_at)
             };
             
-            let content = format!("{}  [{}]", title, status);
-            ListItem::new(content)
+            // Styled on its own so it stands out even on a favorite's yellow line
+            let badge = if game.upset_watch() {
+                vec![Span::raw(" "), Span::styled(" UPSET WATCH ", Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))]
+            } else {
+                vec![]
+            };
+
+            if app.is_favorite_game(game) {
+                let content = format!("★ {}  [{}]", title, status);
+                ListItem::new(Line::from_iter(std::iter::once(Span::raw(content)).chain(badge))).style(Style::default().fg(Color::Yellow))
+            } else {
+                let mut content = format!("{}  [{}]", title, status);
+                if app.sidebar_odds && game.status.type_field.state == GameState::Pre {
+                    let line = game.competitions.first().and_then(|c| c.odds.as_ref()).and_then(|o| o.first()).and_then(|o| o.details.as_ref());
+                    if let Some(line) = line {
+                        content.push_str(&format!("  {}", line));
+                    }
+                }
+                ListItem::new(Line::from_iter(std::iter::once(Span::raw(content)).chain(badge)))
+            }
         })
         .collect();
 
-    let title = if app.filter_live { " LIVE GAMES " } else { " GAMES " };
+    let base_title = if app.filter_live { "LIVE GAMES" } else { "GAMES" };
+    let mut title = match app.week {
+        Some(week) => format!(" {} · WEEK {} ", base_title, week),
+        None => format!(" {} ", base_title),
+    };
+    if let Some(season_type) = app.season_type.filter(|t| *t != SeasonType::Regular) {
+        title.push_str(&format!("· {} ", season_type.label().to_uppercase()));
+    }
+    if let Some(group) = &app.group {
+        title.push_str(&format!("· {} ", data::group_name(group).to_uppercase()));
+    }
+    if app.redzone {
+        title.push_str("· REDZONE ");
+    }
+    if app.rotate {
+        title.push_str("· AUTO ");
+    }
+    if app.sort_mode != SortMode::Kickoff {
+        title.push_str(&format!("· {} ", app.sort_mode.label().to_uppercase()));
+    }
+    if let Some(search) = app.search.as_ref().filter(|s| !s.is_empty()) {
+        title.push_str(&format!("· /{} ", search));
+    }
 
     let games_list = List::new(items)
         .block(Block::default().title(title).borders(Borders::ALL))
@@ -356,28 +2063,231 @@ fn draw_sidebar(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_stateful_widget(games_list, area, &mut app.state);
 }
 
+/// Marks games outside the regular season: "PRE", the NFL playoff round, or "BOWL".
+fn season_label(game: &GameEvent, league: &str) -> Option<&'static str> {
+    let season = game.season.as_ref()?;
+    match season.type_field {
+        1 => Some("PRE"),
+        3 if league == "college-football" => Some("BOWL"),
+        3 => Some(match game.week.as_ref().map(|w| w.number) {
+            Some(1) => "WC",
+            Some(2) => "DIV",
+            Some(3) => "CONF",
+            Some(4) => "PRO BOWL",
+            Some(5) => "SB",
+            _ => "POST",
+        }),
+        _ => None,
+    }
+}
+
+/// "LAR 11-4 @ ATL 6-9" (or "#3 UGA 11-1 @ ..." for ranked college teams), falling back to the plain short name when records are missing.
+fn sidebar_title(game: &GameEvent) -> String {
+    let Some(comp) = game.competitions.first() else {
+        return game.short_name.clone();
+    };
+    let side = |home_away: &str| comp.competitors.iter().find(|c| c.home_away == home_away);
+    match (side("away"), side("home")) {
+        (Some(a), Some(h)) if a.record().is_some() || h.record().is_some() || a.rank().is_some() || h.rank().is_some() => {
+            let label = |c: &Competitor| match c.record() {
+                Some(record) => format!("{} {}", c.label(), record),
+                None => c.label(),
+            };
+            format!("{} @ {}", label(a), label(h))
+        }
+        _ => game.short_name.clone(),
+    }
+}
+
+// One line of score: "BUF 17   Q3 4:12   24 KC", each team in its color
+fn score_line<'a>(app: &App, game: &'a GameEvent, away: &'a Competitor, home: &'a Competitor) -> Line<'a> {
+    let team = |c: &Competitor| {
+        let color = parse_color(c.team.color.as_deref().unwrap_or("000000"));
+        Span::styled(format!(" {} ", c.label()), Style::default().bg(color).fg(text_color_on(color)).add_modifier(Modifier::BOLD))
+    };
+    let score = |c: &Competitor| Span::styled(format!(" {} ", c.score.as_deref().unwrap_or("0")), Style::default().add_modifier(Modifier::BOLD));
+    let status = match game.status.type_field.state {
+        GameState::In => Span::styled(format!("  {} {}  ", game.status.period_label(), game_clock(game, app.refreshed_at)), Style::default().fg(Color::Red)),
+        _ => Span::styled(format!("  {}  ", game.status.type_field.short_detail), Style::default().fg(Color::Gray)),
+    };
+    Line::from(vec![team(away), score(away), status, score(home), team(home)])
+}
+
+// Down and distance with the ball's spot, or the game status between plays
+fn situation_line(game: &GameEvent) -> Line<'_> {
+    let situation = game.competitions.first().and_then(|c| c.situation.as_ref());
+    match situation.and_then(|s| s.short_down_distance_text.as_ref()) {
+        Some(text) => Line::from(Span::styled(format!(" {} ", text), Style::default().bg(Color::White).fg(Color::Black).add_modifier(Modifier::BOLD))),
+        None => Line::from(Span::styled(game.status.type_field.detail.clone(), Style::default().fg(Color::Gray))),
+    }
+}
+
+/// Draws a team's logo as a Braille silhouette or half blocks, or, with a graphics protocol, just
+/// notes where it goes so `place_logos` can send the real image after the frame.
+fn draw_logo(f: &mut Frame, app: &App, abbr: &str, area: Rect) {
+    let Some(img) = app.logos.get(abbr) else {
+        return;
+    };
+    if app.logo_style.is_graphics() {
+        app.logo_spots.borrow_mut().push((abbr.to_string(), logo::fit(area)));
+        return;
+    }
+    if app.logo_style == LogoStyle::HalfBlock {
+        f.render_widget(logo::HalfBlocks(img), area);
+        return;
+    }
+    // Sampling the image is slow enough to notice every frame, so it's done once per size
+    let key = (abbr.to_string(), area.width, area.height);
+    let mut cache = app.logo_points.borrow_mut();
+    let points = cache.entry(key).or_insert_with(|| logo::braille_points(img, area.width, area.height));
+    let canvas = Canvas::default()
+        .block(Block::default())
+        .marker(Marker::Braille)
+        .x_bounds([0.0, (area.width * 2) as f64 - 1.0])
+        .y_bounds([0.0, (area.height * 4) as f64 - 1.0])
+        .paint(|ctx| {
+            for (color, coords) in points.iter() {
+                ctx.draw(&Points { coords, color: *color });
+            }
+        });
+    f.render_widget(canvas, area);
+}
+
+/// Sends the logos `draw_logo` set aside straight to the terminal. They're only
+/// resent when they move, since the terminal keeps them between frames.
+fn place_logos<B: Backend + Write>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()> {
+    let mut spots = app.logo_spots.take();
+    if app.show_help || app.group_picker.is_some() {
+        spots.clear();
+    }
+    if spots == app.placed_logos {
+        return Ok(());
+    }
+    if app.logo_style == LogoStyle::Iterm2 && !app.placed_logos.is_empty() {
+        // The old images only go away when their cells are redrawn; place the new ones next frame
+        terminal.clear()?;
+        app.placed_logos.clear();
+        return Ok(());
+    }
+
+    let backend = terminal.backend_mut();
+    queue!(backend, Print(logo::clear(app.logo_style)))?;
+    for (abbr, area) in &spots {
+        if !app.logo_payloads.contains_key(abbr) {
+            let Some(payload) = app.logos.get(abbr).and_then(logo::encode) else { continue };
+            app.logo_payloads.insert(abbr.clone(), payload);
+        }
+        let payload = &app.logo_payloads[abbr];
+        queue!(backend, MoveTo(area.x, area.y), Print(logo::escape(app.logo_style, payload, area.width, area.height)))?;
+    }
+    Write::flush(backend)?;
+    app.placed_logos = spots;
+    Ok(())
+}
+
+// A game cut down to its score, situation and field, for the split view
+fn draw_game_pane(f: &mut Frame, app: &App, game: &GameEvent, pinned: bool, area: Rect) {
+    let title = if pinned { format!(" PINNED · {} ", game.short_name) } else { format!(" {} ", game.short_name) };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let Some(comp) = game.competitions.first() else { return };
+    let side = |home_away: &str| comp.competitors.iter().find(|c| c.home_away == home_away);
+    let (Some(a), Some(h)) = (side("away"), side("home")) else { return };
+
+    let rows = Layout::vertical([Constraint::Length(1), Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)]).split(inner);
+    f.render_widget(Paragraph::new(score_line(app, game, a, h)).alignment(Alignment::Center), rows[0]);
+    f.render_widget(Paragraph::new(situation_line(game)).alignment(Alignment::Center), rows[2]);
+    if game.status.type_field.state != GameState::Pre {
+        let field = FieldDisplay {
+            home: h,
+            away: a,
+            comp,
+            status: &game.status,
+            moved: app.scrimmage_moves.get(&game.id).copied(),
+        };
+        f.render_widget(field, rows[3].inner(Margin { horizontal: 0, vertical: 1 }));
+    }
+}
+
 fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
-    let block = Block::default().borders(Borders::ALL);
+    // League-wide screens replace the game panel entirely
+    match app.view {
+        View::Standings => {
+            views::standings::draw_standings(f, app.standings.as_ref(), app.detail_scroll, area);
+            return;
+        }
+        View::News => {
+            views::news::draw_news(f, app.news.as_ref(), app.detail_scroll, area);
+            return;
+        }
+        View::Rankings => {
+            views::rankings::draw_rankings(f, app.rankings.as_ref(), app.detail_scroll, area);
+            return;
+        }
+        View::Bracket => {
+            views::bracket::draw_bracket(f, app.bracket.as_ref(), area);
+            return;
+        }
+        View::Bowls => {
+            views::bowls::draw_bowls(f, app.bowls.as_deref(), app.detail_scroll, area);
+            return;
+        }
+        _ => {}
+    }
+
+    // Split view: the pinned game on the left, whatever's selected on the right
+    let pinned = app.split.as_ref().and_then(|id| app.events.iter().find(|e| e.id == *id));
+    if let (Some(pinned), View::Game) = (pinned, app.view) {
+        let panes = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).split(area);
+        draw_game_pane(f, app, pinned, true, panes[0]);
+        if let Some(game) = app.selected_event() {
+            draw_game_pane(f, app, game, false, panes[1]);
+        }
+        return;
+    }
+
+    let mut block = Block::default().borders(Borders::ALL);
+    if let Some(subtitle) = app.selected_event().and_then(|g| g.subtitle()) {
+        block = block.title(Line::from(format!(" {} ", subtitle)).centered());
+    }
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let events = app.filtered_events();
-    let selected_index = app.state.selected().unwrap_or(0);
-
-    if let Some(game) = events.get(selected_index) {
+    if let Some(game) = app.selected_event() {
         if let Some(comp) = game.competitions.first() {
             let home = comp.competitors.iter().find(|c| c.home_away == "home");
             let away = comp.competitors.iter().find(|c| c.home_away == "away");
 
             if let (Some(h), Some(a)) = (home, away) {
+                // Small terminals get the score on one line and the details under it; no big text or field
+                if app.compact {
+                    let rows = Layout::vertical([Constraint::Length(1), Constraint::Length(1), Constraint::Length(1), Constraint::Min(0)]).split(inner_area);
+                    f.render_widget(Paragraph::new(score_line(app, game, a, h)).alignment(Alignment::Center), rows[0]);
+                    f.render_widget(Paragraph::new(situation_line(game)).alignment(Alignment::Center), rows[1]);
+                    draw_game_details(f, app, game, a, h, rows[3]);
+                    return;
+                }
+
+                let summary = app.summaries.get(&game.id);
+                let drives = summary.map(|s| s.drives()).unwrap_or_default();
+
+                // Summary views trade the field for a taller details pane
+                let (field_constraint, chart_height) = if app.view.hides_field() {
+                    (Constraint::Length(0), 0)
+                } else {
+                    (Constraint::Min(6), drives.len().min(views::drive_chart::MAX_ROWS) as u16)
+                };
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .constraints([
-                        Constraint::Length(16), // Scoreboard Header
-                        Constraint::Min(6),     // Field Display (Allow shrinking)
-                        Constraint::Length(1),  // Spacer
-                        Constraint::Length(3),  // Status Bar
-                        Constraint::Min(0),     // Details
+                        Constraint::Length(16),           // Scoreboard Header
+                        field_constraint,                 // Field Display (Allow shrinking)
+                        Constraint::Length(chart_height), // Drive Chart
+                        Constraint::Length(1),            // Spacer
+                        Constraint::Length(3),            // Status Bar
+                        Constraint::Min(0),               // Details
                     ].as_ref())
                     .split(inner_area);
 
@@ -407,35 +2317,9 @@ fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
                     .constraints(a_constraints.as_ref()) 
                     .split(header_chunks[0]);
 
-                  if app.show_logos {
-                      if let Some(img) = app.logos.get(&a.team.abbreviation) {
-                         let canvas = Canvas::default()
-                            .block(Block::default())
-                            .marker(Marker::Braille)
-                            .x_bounds([0.0, 40.0])
-                            .y_bounds([0.0, 20.0])
-                            .paint(|ctx| {
-                                let (w, h) = img.dimensions();
-                                for y in 0..40 {
-                                    for x in 0..80 {
-                                        let img_x = (x as f64 / 80.0 * w as f64) as u32;
-                                        let img_y = (y as f64 / 40.0 * h as f64) as u32;
-                                        if img_x < w && img_y < h {
-                                             let p = img.get_pixel(img_x, img_y);
-                                             let alpha = p[3];
-                                             if alpha > 128 {
-                                                  ctx.draw(&Points {
-                                                      coords: &[(x as f64 / 2.0, 20.0 - (y as f64 / 2.0))],
-                                                      color: Color::Rgb(p[0], p[1], p[2]),
-                                                  });
-                                             }
-                                        }
-                                    }
-                                }
-                            });
-                          f.render_widget(canvas, a_content_chunks[0]);
-                     }
-                  }
+                if app.show_logos {
+                    draw_logo(f, app, &a.team.abbreviation, a_content_chunks[0]);
+                }
 
 
                 // Stacked Big Text (Abbr + Score)
@@ -481,16 +2365,37 @@ fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
                     f.render_widget(a_score_text, a_text_area[2]);
                 }
 
-                // Possession Indicator
-                if let Some(sit) = &comp.situation {
-                    if let Some(pos_id) = &sit.possession {
-                        if pos_id == &a.team.id.clone().unwrap_or_default() {
-                            let p = Paragraph::new("🏈").alignment(Alignment::Center);
-                            f.render_widget(p, a_text_area[3]);
-                        }
-                    }
+                // Win Probability (live games only)
+                let win_prob = if game.status.type_field.state == GameState::In {
+                    comp.situation
+                        .as_ref()
+                        .and_then(|s| s.last_play.as_ref())
+                        .and_then(|lp| lp.probability.as_ref())
+                        .or_else(|| summary.and_then(|s| s.winprobability.last()))
+                        .map(|p| p.split())
+                } else {
+                    None
+                };
+                if let Some((away_pct, _)) = win_prob {
+                    let p = Paragraph::new(format!("WIN {:.0}%", away_pct * 100.0))
+                        .style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+                        .alignment(Alignment::Center);
+                    f.render_widget(p, a_text_area[0]);
                 }
 
+                // Poll rank and season record, with the possession indicator beside them
+                let a_has_ball = comp
+                    .situation
+                    .as_ref()
+                    .and_then(|s| s.possession.as_ref())
+                    .is_some_and(|pos_id| pos_id == &a.team.id.clone().unwrap_or_default());
+                let a_rank = a.rank().map(|r| format!("#{}", r));
+                let a_footer = [a_has_ball.then_some(app.possession_marker.as_str()).filter(|m| !m.is_empty()), a_rank.as_deref(), a.record()].into_iter().flatten().collect::<Vec<_>>().join(" ");
+                let p = Paragraph::new(a_footer)
+                    .style(Style::default().fg(Color::White))
+                    .alignment(Alignment::Center);
+                f.render_widget(p, a_text_area[3]);
+
 
                 // Home Team
                 let h_color = parse_color(h.team.color.as_deref().unwrap_or("000000"));
@@ -550,72 +2455,110 @@ fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
                     f.render_widget(h_big_text, h_text_area[2]);
                 }
                 
-                // Possession Indicator
-                if let Some(sit) = &comp.situation {
-                    if let Some(pos_id) = &sit.possession {
-                        if pos_id == &h.team.id.clone().unwrap_or_default() {
-                            let p = Paragraph::new("🏈").alignment(Alignment::Center);
-                            f.render_widget(p, h_text_area[3]);
-                        }
-                    }
+                if let Some((_, home_pct)) = win_prob {
+                    let p = Paragraph::new(format!("WIN {:.0}%", home_pct * 100.0))
+                        .style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+                        .alignment(Alignment::Center);
+                    f.render_widget(p, h_text_area[0]);
                 }
 
-                  if app.show_logos {
-                      if let Some(img) = app.logos.get(&h.team.abbreviation) {
-                         let canvas = Canvas::default()
-                            .block(Block::default())
-                            .marker(Marker::Braille)
-                            .x_bounds([0.0, 40.0])
-                            .y_bounds([0.0, 20.0])
-                            .paint(|ctx| {
-                                let (w, h) = img.dimensions();
-                                for y in 0..40 {
-                                    for x in 0..80 {
-                                        let img_x = (x as f64 / 80.0 * w as f64) as u32;
-                                        let img_y = (y as f64 / 40.0 * h as f64) as u32;
-                                        if img_x < w && img_y < h {
-                                             let p = img.get_pixel(img_x, img_y);
-                                             let alpha = p[3];
-                                             if alpha > 128 {
-                                                  ctx.draw(&Points {
-                                                      coords: &[(x as f64 / 2.0, 20.0 - (y as f64 / 2.0))],
-                                                      color: Color::Rgb(p[0], p[1], p[2]),
-                                                  });
-                                             }
-                                        }
-                                    }
-                                }
-                            });
-                          f.render_widget(canvas, h_content_chunks[1]);
-                     }
-                  }
+                // Poll rank and season record, with the possession indicator beside them
+                let h_has_ball = comp
+                    .situation
+                    .as_ref()
+                    .and_then(|s| s.possession.as_ref())
+                    .is_some_and(|pos_id| pos_id == &h.team.id.clone().unwrap_or_default());
+                let h_rank = h.rank().map(|r| format!("#{}", r));
+                let h_footer = [h_has_ball.then_some(app.possession_marker.as_str()).filter(|m| !m.is_empty()), h_rank.as_deref(), h.record()].into_iter().flatten().collect::<Vec<_>>().join(" ");
+                let p = Paragraph::new(h_footer)
+                    .style(Style::default().fg(Color::White))
+                    .alignment(Alignment::Center);
+                f.render_widget(p, h_text_area[3]);
+
+                if app.show_logos {
+                    draw_logo(f, app, &h.team.abbreviation, h_content_chunks[1]);
+                }
                 
 
                 // Middle
-                let status_color = if game.status.type_field.state == "in" { Color::Red } else { Color::Gray };
+                let status_color = if game.status.type_field.state == GameState::In { Color::Red } else { Color::Gray };
                 
-                let league_display = if app.league_label == "college-football" { "NCAA" } else { "NFL" };
-                let mid_text = vec![
+                let league_display = data::league_name(&app.league_label);
+                let mut mid_text = vec![
                     Line::from(Span::styled(league_display, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
                     Line::from(Span::styled("VS", Style::default().add_modifier(Modifier::ITALIC))),
                     Line::from(""),
-                    Line::from(Span::styled(&game.status.display_clock, Style::default().fg(status_color).add_modifier(Modifier::BOLD))),
-                    Line::from(format!("Q{}", game.status.period)),
                 ];
+                match game.kickoff().filter(|_| game.status.type_field.state == GameState::Pre) {
+                    Some(kickoff) => {
+                        mid_text.push(Line::from(Span::styled("Kickoff in", Style::default().fg(Color::Gray))));
+                        mid_text.push(Line::from(Span::styled(countdown(kickoff), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))));
+                    }
+                    None => {
+                        mid_text.push(Line::from(Span::styled(game_clock(game, app.refreshed_at), Style::default().fg(status_color).add_modifier(Modifier::BOLD))));
+                        if game.status.is_overtime() {
+                            let badge = format!(" {} ", game.status.period_label());
+                            mid_text.push(Line::from(Span::styled(badge, Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))));
+                        } else {
+                            mid_text.push(Line::from(game.status.period_label()));
+                        }
+                    }
+                }
                 let mid_p = Paragraph::new(mid_text).alignment(Alignment::Center);
                 f.render_widget(mid_p, header_chunks[1]);
 
-                // --- Field Display ---
-                let field_display = FieldDisplay {
-                    home: h,
-                    away: a,
-                    comp,
-                };
-                f.render_widget(field_display, chunks[1]);
+                // Linescore below the clock, once any points have been tallied
+                if a.linescores.as_ref().is_some_and(|l| !l.is_empty()) {
+                    let mid_area = header_chunks[1];
+                    let table_area = Rect {
+                        y: mid_area.y + 6,
+                        height: mid_area.height.saturating_sub(6).min(3),
+                        ..mid_area
+                    };
+                    f.render_widget(linescore_table(a, h), table_area);
+                }
+
+                // --- Field Display (or the matchup preview before kickoff) ---
+                if game.status.type_field.state == GameState::Pre {
+                    if chunks[1].height > 0 {
+                        views::preview::draw_preview(f, game, comp, summary, a, h, chunks[1]);
+                    }
+                } else {
+                    let field_display = FieldDisplay {
+                        home: h,
+                        away: a,
+                        comp,
+                        status: &game.status,
+                        moved: app.scrimmage_moves.get(&game.id).copied(),
+                    };
+                    f.render_widget(field_display, chunks[1]);
+                }
+
+                // --- Drive Chart ---
+                if chart_height > 0 {
+                    let drive_chart = views::drive_chart::DriveChart {
+                        drives: &drives,
+                        home: h,
+                        away: a,
+                    };
+                    f.render_widget(drive_chart, chunks[2]);
+                }
 
 
                 // --- Status Bar ---
                 let mut status_line_content = vec![];
+                // Flash for a few seconds after RedZone mode switches games, or a turnover
+                // and a steady tag for as long as the offense stays inside the 20
+                let red_zone_tag = app
+                    .redzone_alert
+                    .and_then(|since| flash(" RED ZONE ", Color::Red, since))
+                    .or_else(|| is_red_zone(game).then(|| Span::styled(" RED ZONE ", Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))));
+                if let Some(span) = red_zone_tag {
+                    status_line_content.extend([span, Span::raw(" ")]);
+                }
+                if let Some(span) = app.turnovers.get(&game.id).and_then(|since| flash(" TURNOVER ", Color::Magenta, *since)) {
+                    status_line_content.extend([span, Span::raw(" ")]);
+                }
                 if let Some(sit) = &comp.situation {
                     if let Some(dd_text) = &sit.short_down_distance_text {
                         status_line_content.push(Span::styled(format!(" {} ", dd_text), Style::default().bg(Color::White).fg(Color::Black).add_modifier(Modifier::BOLD)));
@@ -642,31 +2585,57 @@ fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
                     status_line_content.push(Span::raw(format!("  {}", game.status.type_field.detail)));
                 }
 
-                // Broadcast Info
-                if let Some(broadcasts) = &comp.broadcasts {
-                    let names: Vec<String> = broadcasts.iter().flat_map(|b| b.names.clone()).collect();
-                    if !names.is_empty() {
-                         status_line_content.push(Span::styled(format!("  [TV: {}]", names.join(", ")), Style::default().fg(Color::Cyan)));
+                // A flag on the last play
+                if let Some(penalty) = comp.situation.as_ref().and_then(|s| s.last_play.as_ref()).and_then(|p| p.penalty()) {
+                    status_line_content.push(Span::raw("  "));
+                    status_line_content.push(Span::styled(" FLAG ", Style::default().bg(Color::Yellow).fg(Color::Black).add_modifier(Modifier::BOLD)));
+                    status_line_content.push(Span::styled(format!(" {}", penalty), Style::default().fg(Color::Yellow)));
+                }
+
+                // Time of possession, with whoever has held the ball longer in bold
+                let boxscore_teams = summary.and_then(|s| s.boxscore.as_ref()).map(|b| b.teams.as_slice()).unwrap_or_default();
+                if game.status.type_field.state != GameState::Pre && boxscore_teams.len() == 2 && boxscore_teams.iter().all(|t| t.possession_secs().is_some()) {
+                    let longest = boxscore_teams.iter().filter_map(|t| t.possession_secs()).max();
+                    status_line_content.push(Span::raw("  TOP "));
+                    for (i, team) in boxscore_teams.iter().enumerate() {
+                        let style = if team.possession_secs() == longest {
+                            Style::default().add_modifier(Modifier::BOLD)
+                        } else {
+                            Style::default().fg(Color::Gray)
+                        };
+                        let separator = if i == 0 { "" } else { " · " };
+                        status_line_content.push(Span::styled(
+                            format!("{}{} {}", separator, team.team.abbreviation.as_deref().unwrap_or("?"), team.stat("possessionTime").unwrap_or_default()),
+                            style,
+                        ));
+                    }
+                }
+
+                // Broadcast Info, split into national TV, local TV and streaming when ESPN says which is which
+                if comp.geo_broadcasts.is_empty() {
+                    if let Some(broadcasts) = &comp.broadcasts {
+                        let names: Vec<String> = broadcasts.iter().flat_map(|b| b.names.clone()).collect();
+                        if !names.is_empty() {
+                             status_line_content.push(Span::styled(format!("  [TV: {}]", names.join(", ")), Style::default().fg(Color::Cyan)));
+                        }
+                    }
+                } else {
+                    for (coverage, label, color) in [(Coverage::National, "TV", Color::Cyan), (Coverage::Local, "Local", Color::Blue), (Coverage::Streaming, "Stream", Color::Magenta)] {
+                        let mut names: Vec<&str> = comp.geo_broadcasts.iter().filter(|b| b.coverage() == Some(coverage)).filter_map(|b| b.name()).collect();
+                        names.dedup();
+                        if !names.is_empty() {
+                            status_line_content.push(Span::styled(format!("  [{}: {}]", label, names.join(", ")), Style::default().fg(color)));
+                        }
                     }
                 }
                 
                 let val_status_line = Line::from(status_line_content);
                 let status_bar = Paragraph::new(val_status_line).block(Block::default().borders(Borders::TOP | Borders::BOTTOM));
-                f.render_widget(status_bar, chunks[3]);
+                f.render_widget(status_bar, chunks[4]);
 
 
                 // --- Details ---
-                if let Some(sit) = &comp.situation {
-                    if let Some(lp) = &sit.last_play {
-                        let details = vec![
-                            Line::from(Span::styled("Last Play", Style::default().add_modifier(Modifier::UNDERLINED))),
-                            Line::from(""),
-                            Line::from(lp.text.clone()),
-                        ];
-                        let details_p = Paragraph::new(details).wrap(Wrap { trim: true });
-                        f.render_widget(details_p, chunks[4]);
-                    }
-                }
+                draw_game_details(f, app, game, a, h, chunks[5]);
             }
         }
     } else {
@@ -675,12 +2644,221 @@ fn draw_main_panel(f: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+// Under the status bar: the open per-game view, or the last play (recap once it's over),
+// leaders, and the scoring summary
+fn draw_game_details(f: &mut Frame, app: &App, game: &GameEvent, a: &Competitor, h: &Competitor, area: Rect) {
+    let Some(comp) = game.competitions.first() else { return };
+    let summary = app.summaries.get(&game.id);
+    if app.view == View::Plays {
+        views::plays::draw_plays(f, summary, app.detail_scroll, area);
+    } else if app.view == View::BoxScore {
+        views::boxscore::draw_boxscore(f, summary, app.detail_scroll, area);
+    } else if app.view == View::HeadToHead {
+        let meetings = match (&a.team.id, &h.team.id) {
+            (Some(away_id), Some(home_id)) => app.head_to_head.get(&data::matchup_key(away_id, home_id)),
+            _ => None,
+        };
+        views::head_to_head::draw_head_to_head(f, meetings.map(|m| m.as_slice()), area);
+    } else if let Some(odds) = comp.odds.as_ref().and_then(|o| o.first()).filter(|_| game.status.type_field.state == GameState::Pre) {
+        let details_p = Paragraph::new(odds_lines(odds, a, h)).wrap(Wrap { trim: true });
+        f.render_widget(details_p, area);
+    } else {
+        // The scoring summary takes whatever room is left under the last play and leaders
+        let scoring_plays = summary.map(|s| s.scoring_plays()).unwrap_or_default();
+        let (top_area, scoring_area) = if scoring_plays.is_empty() {
+            (area, None)
+        } else {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(8), Constraint::Min(0)])
+                .split(area);
+            (rows[0], Some(rows[1]))
+        };
+        if let Some(scoring_area) = scoring_area {
+            views::scoring::draw_scoring_summary(f, &scoring_plays, a, h, scoring_area);
+        }
+
+        // Leaders sit beside the last play once the game is underway
+        let leaders = comp.leaders.as_deref().unwrap_or_default();
+        let show_leaders = game.status.type_field.state != GameState::Pre && !leaders.is_empty();
+        let detail_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(if show_leaders {
+                [Constraint::Percentage(50), Constraint::Percentage(50)]
+            } else {
+                [Constraint::Percentage(100), Constraint::Length(0)]
+            })
+            .split(top_area);
+
+        // The last play means nothing once the game is over; the recap says more
+        let recap = summary
+            .and_then(|s| s.article.as_ref())
+            .filter(|_| game.status.type_field.state == GameState::Post);
+        if let Some(recap) = recap {
+            let mut details = vec![
+                Line::from(Span::styled("Recap", Style::default().add_modifier(Modifier::UNDERLINED))),
+                Line::from(""),
+                Line::from(Span::styled(recap.headline.clone(), Style::default().add_modifier(Modifier::BOLD))),
+            ];
+            if let Some(description) = &recap.description {
+                details.push(Line::from(""));
+                details.push(Line::from(Span::styled(description.clone(), Style::default().fg(Color::Gray))));
+            }
+            let details_p = Paragraph::new(details).wrap(Wrap { trim: true });
+            f.render_widget(details_p, detail_chunks[0]);
+        } else if let Some(lp) = comp.situation.as_ref().and_then(|s| s.last_play.as_ref()) {
+            let details = vec![
+                Line::from(Span::styled("Last Play", Style::default().add_modifier(Modifier::UNDERLINED))),
+                Line::from(""),
+                Line::from(lp.text.clone()),
+            ];
+            let details_p = Paragraph::new(details).wrap(Wrap { trim: true });
+            f.render_widget(details_p, detail_chunks[0]);
+        }
+
+        if show_leaders {
+            let leaders_p = Paragraph::new(leader_lines(leaders, &comp.competitors)).wrap(Wrap { trim: true });
+            f.render_widget(leaders_p, detail_chunks[1]);
+        }
+    }
+}
+
+// A blinking status bar badge for the first few seconds after `since`
+fn flash(text: &'static str, color: Color, since: Instant) -> Option<Span<'static>> {
+    let elapsed = since.elapsed();
+    if elapsed >= FLASH_DURATION {
+        return None;
+    }
+    let style = if (elapsed.as_millis() / 500).is_multiple_of(2) {
+        Style::default().bg(color).fg(Color::White)
+    } else {
+        Style::default().fg(color)
+    };
+    Some(Span::styled(text, style.add_modifier(Modifier::BOLD)))
+}
+
+fn linescore_table(away: &Competitor, home: &Competitor) -> Table<'static> {
+    let periods = |c: &Competitor| c.linescores.as_ref().map(|l| l.len()).unwrap_or(0);
+    // Always show four quarters, plus however many overtimes were played
+    let columns = periods(away).max(periods(home)).max(4);
+
+    let header = std::iter::once(String::new())
+        .chain((1..=columns).map(|p| match p {
+            1..=4 => p.to_string(),
+            5 => "OT".to_string(),
+            _ => format!("O{}", p - 4),
+        }))
+        .chain(std::iter::once("T".to_string()));
+    let row = |c: &Competitor| {
+        let scores = c.linescores.clone().unwrap_or_default();
+        let cells = (0..columns).map(move |i| scores.get(i).map(|l| (l.value as i64).to_string()).unwrap_or_default());
+        Row::new(
+            std::iter::once(c.team.abbreviation.clone())
+                .chain(cells)
+                .chain(std::iter::once(c.score.clone().unwrap_or_default())),
+        )
+    };
+
+    let widths = std::iter::once(Constraint::Length(4))
+        .chain(std::iter::repeat_n(Constraint::Length(2), columns))
+        .chain(std::iter::once(Constraint::Length(3)));
+    Table::new([row(away), row(home)], widths)
+        .header(Row::new(header).style(Style::default().fg(Color::DarkGray)))
+        .column_spacing(1)
+        .flex(Flex::Center)
+}
+
+fn leader_lines<'a>(leaders: &'a [LeaderCategory], competitors: &[Competitor]) -> Vec<Line<'a>> {
+    let mut lines = vec![
+        Line::from(Span::styled("Leaders", Style::default().add_modifier(Modifier::UNDERLINED))),
+        Line::from(""),
+    ];
+    for category in leaders {
+        let Some(leader) = category.leaders.first() else {
+            continue;
+        };
+        let label = category.short_display_name.as_deref().unwrap_or(&category.name);
+        let name = leader
+            .athlete
+            .as_ref()
+            .map(|a| a.short_name.clone().unwrap_or_else(|| a.display_name.clone()))
+            .unwrap_or_default();
+        let team_id = leader.team.as_ref().and_then(|t| t.id.as_ref());
+        let abbr = competitors
+            .iter()
+            .find(|c| c.team.id.as_ref().is_some() && c.team.id.as_ref() == team_id)
+            .map(|c| format!(" ({})", c.team.abbreviation))
+            .unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<5}", label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{}{}", name, abbr), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("  {}", leader.display_value)),
+        ]));
+    }
+    lines
+}
+
+fn odds_lines<'a>(odds: &'a Odds, away: &Competitor, home: &Competitor) -> Vec<Line<'a>> {
+    let provider = odds.provider.as_ref().and_then(|p| p.name.as_deref()).unwrap_or("Odds");
+    let mut lines = vec![
+        Line::from(Span::styled(format!("Odds ({})", provider), Style::default().add_modifier(Modifier::UNDERLINED))),
+        Line::from(""),
+    ];
+
+    let mut line_spans = vec![];
+    if let Some(details) = &odds.details {
+        line_spans.push(Span::styled(format!("Line: {}", details), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+    }
+    if let Some(total) = odds.over_under {
+        line_spans.push(Span::raw(format!("    O/U: {}", total)));
+    }
+    if !line_spans.is_empty() {
+        lines.push(Line::from(line_spans));
+    }
+
+    let money_line = |team_odds: Option<&TeamOdds>, abbr: &str| {
+        team_odds.and_then(|o| o.money_line).map(|ml| format!("{} {:+}", abbr, ml.round() as i64))
+    };
+    let money_lines: Vec<String> = [
+        money_line(odds.away_team_odds.as_ref(), &away.team.abbreviation),
+        money_line(odds.home_team_odds.as_ref(), &home.team.abbreviation),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !money_lines.is_empty() {
+        lines.push(Line::from(format!("Moneyline: {}", money_lines.join("  "))));
+    }
+    lines
+}
+
 use ratatui::widgets::Widget;
 
+// How long the scrimmage marker takes to slide to its new spot
+const SCRIMMAGE_SLIDE: Duration = Duration::from_millis(800);
+
+/// The line of scrimmage in field yards: 0-120 left to right, end zones included, with
+/// the away team driving left to right.
+fn scrimmage_yard(event: &GameEvent) -> Option<f64> {
+    let comp = event.competitions.first()?;
+    let sit = comp.situation.as_ref()?;
+    let yl = sit.yard_line? as f64;
+    let away_id = comp.competitors.iter().find(|c| c.home_away == "away").and_then(|c| c.team.id.as_ref());
+    // yard_line counts down to the goal the offense is attacking
+    if sit.possession.is_some() && sit.possession.as_ref() == away_id {
+        Some(110.0 - yl)
+    } else {
+        Some(10.0 + yl)
+    }
+}
+
 struct FieldDisplay<'a> {
     home: &'a crate::model::Competitor,
     away: &'a crate::model::Competitor,
     comp: &'a crate::model::Competition,
+    status: &'a crate::model::Status,
+    // The previous scrimmage yard and when the line moved from it
+    moved: Option<(f64, Instant)>,
 }
 
 impl<'a> Widget for FieldDisplay<'a> {
@@ -695,7 +2873,20 @@ impl<'a> Widget for FieldDisplay<'a> {
 
         // Field Dimensions: 0-120 yards (10 EZ + 100 Field + 10 EZ)
         // Map area.width columns to 120 yards.
-        
+
+        let final_whistle = self.status.type_field.state == GameState::Post;
+
+        // The 20 yards in front of the goal the offense is attacking, while it's in the red zone.
+        // Away drives left to right.
+        let red_zone = self.comp.situation.as_ref().filter(|s| s.is_red_zone == Some(true)).and_then(|s| {
+            let possession = s.possession.as_ref()?;
+            if Some(possession) == self.away.team.id.as_ref() {
+                Some(90.0..110.0)
+            } else {
+                Some(10.0..30.0)
+            }
+        });
+
         for x in area.left()..area.right() {
             for y in area.top()..area.bottom() {
                 // Determine logical yard (0.0 to 120.0)
@@ -715,7 +2906,13 @@ impl<'a> Widget for FieldDisplay<'a> {
                     cell.set_bg(home_color);
                 } else {
                     // Field
-                    cell.set_bg(Color::Rgb(0, 150, 0)); // Darker Green
+                    if red_zone.as_ref().is_some_and(|r| r.contains(&logical_yard)) {
+                        cell.set_bg(Color::Rgb(150, 60, 40)); // Red Zone Tint
+                    } else if final_whistle {
+                        cell.set_bg(Color::Rgb(0, 70, 0)); // Dimmed once it's over
+                    } else {
+                        cell.set_bg(Color::Rgb(0, 150, 0)); // Darker Green
+                    }
                     
                     // 10-yard lines
                     // Just simple lines
@@ -723,10 +2920,24 @@ impl<'a> Widget for FieldDisplay<'a> {
             }
         }
 
+        let col_for = |yard: f64| area.left() + (yard / 120.0 * area.width as f64) as u16;
+
+        // Hash marks: two faint rows of ticks, one per yard, once there's room between them
+        if area.height >= 5 {
+            let hash_rows = [area.top() + area.height / 3, area.top() + area.height * 2 / 3];
+            for yard in (11..110).filter(|y| y % 10 != 0) {
+                let col = col_for(yard as f64);
+                for y in hash_rows {
+                    if let Some(cell) = buf.cell_mut((col, y)) {
+                        cell.set_fg(Color::Rgb(120, 200, 120)).set_char('·');
+                    }
+                }
+            }
+        }
+
         // Draw Yard Lines (White Vertical Lines)
         for yard in (20..=100).step_by(10) {
-            let pct = yard as f64 / 120.0;
-            let col = area.left() + (pct * area.width as f64) as u16;
+            let col = col_for(yard as f64);
             if col < area.right() {
                 for y in area.top()..area.bottom() {
                      if let Some(cell) = buf.cell_mut((col, y)) {
@@ -736,10 +2947,27 @@ impl<'a> Widget for FieldDisplay<'a> {
             }
         }
 
+        // Yard numbers straddle their line ("1|0"), top and bottom, when the lines are far enough apart
+        if area.height >= 3 && area.width >= 60 {
+            for yard in (20..=100).step_by(10) {
+                let number = 50 - (yard - 60i32).abs();
+                let digits: Vec<char> = number.to_string().chars().collect();
+                let col = col_for(yard as f64);
+                for y in [area.top(), area.bottom() - 1] {
+                    if let [tens, ones] = digits[..] {
+                        for (x, digit) in [(col - 1, tens), (col + 1, ones)] {
+                            if let Some(cell) = buf.cell_mut((x, y)) {
+                                cell.set_fg(Color::White).set_char(digit).set_style(Style::default().add_modifier(Modifier::BOLD));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
         // Game Markers
         if let Some(sit) = &self.comp.situation {
             if let Some(yl) = sit.yard_line {
-                 let mut field_col = 0;
                  let mut is_away_pos = false;
 
                  if let Some(pos_id) = &sit.possession {
@@ -762,8 +2990,15 @@ impl<'a> Widget for FieldDisplay<'a> {
                      10.0 + yl as f64
                  };
                  
-                 let pct = logical_loc / 120.0;
-                 field_col = area.left() + (pct * area.width as f64) as u16;
+                 // Slide in from the previous spot, easing out as it arrives
+                 let marker_loc = match self.moved {
+                     Some((from, since)) if since.elapsed() < SCRIMMAGE_SLIDE => {
+                         let t = since.elapsed().as_secs_f64() / SCRIMMAGE_SLIDE.as_secs_f64();
+                         from + (logical_loc - from) * (1.0 - (1.0 - t).powi(3))
+                     }
+                     _ => logical_loc,
+                 };
+                 let field_col = col_for(marker_loc);
 
                  // Scrimmage Line (White)
                  if field_col < area.right() {
@@ -784,40 +3019,86 @@ impl<'a> Widget for FieldDisplay<'a> {
                       } else {
                           logical_loc - dist as f64
                       };
-                      let fd_pct = fd_loc / 120.0;
-                      let fd_col = area.left() + (fd_pct * area.width as f64) as u16;
+                      let fd_col = col_for(fd_loc);
                       
                       if fd_col < area.right() && fd_col != field_col { // Don't overwrite LoS completely if same
                           for y in area.top()..area.bottom() {
                               if let Some(cell) = buf.cell_mut((fd_col, y)) {
-                                  cell.set_bg(Color::Yellow).set_char(' '); // Solid Yellow Block
+                                  // Dashed as well as yellow, so it still reads without color or on top of a yard line
+                                  cell.set_bg(Color::Yellow).set_fg(Color::Black).set_char('┆');
                               }
                           }
                       }
                  }
+
+                 // Drive direction: an arrow off the line of scrimmage pointing the way the offense is going
+                 if sit.possession.is_some() {
+                     let mid_y = area.top() + area.height / 2;
+                     let (cols, arrow) = if is_away_pos {
+                         ([field_col + 1, field_col + 2], ['─', '▶'])
+                     } else {
+                         ([field_col.saturating_sub(2), field_col.saturating_sub(1)], ['◀', '─'])
+                     };
+                     for (x, ch) in cols.into_iter().zip(arrow) {
+                         if x >= area.left() && x < area.right() {
+                             if let Some(cell) = buf.cell_mut((x, mid_y)) {
+                                 cell.set_fg(Color::White).set_char(ch).set_style(Style::default().add_modifier(Modifier::BOLD));
+                             }
+                         }
+                     }
+                 }
             }
         }
         
         // Text Labels (Overlaid on End Zones)
-        let away_label = &self.away.team.abbreviation;
-        let home_label = &self.home.team.abbreviation;
-        
-        // Simple write at center of EZ
-        // Middle Y
-        let mid_y = area.top() + area.height / 2;
-        
-        // Away Label (~Yard 5) -> 5/120 width
-        let away_col = area.left() + ((5.0 / 120.0) * area.width as f64) as u16;
-        if let Some(cell) = buf.cell_mut((away_col.saturating_sub(1), mid_y)) {
-             cell.set_symbol(away_label);
-        }
-        // Using `set_string` or similar if available, or just writing chars manually?
-        // Buffer has `set_string(x, y, string, style)`
-        if area.width > 20 {
-             buf.set_string(area.left(), mid_y, away_label, Style::default().fg(Color::White).bg(away_color));
-             let h_len = home_label.len() as u16;
-             buf.set_string(area.right().saturating_sub(h_len), mid_y, home_label, Style::default().fg(Color::White).bg(home_color));
+        for (team, color, center) in [(&self.away.team, away_color, 5.0), (&self.home.team, home_color, 115.0)] {
+            let style = Style::default().fg(text_color_on(color)).bg(color).add_modifier(Modifier::BOLD);
+            // Stacked letters down the end zone, the nickname when it fits and the abbreviation otherwise
+            let name = team.short_display_name.to_uppercase();
+            let label = [name.as_str(), team.abbreviation.as_str()]
+                .into_iter()
+                .find(|l| !l.is_empty() && l.chars().count() <= area.height as usize);
+            match label {
+                Some(label) if area.height >= 3 => {
+                    let len = label.chars().count() as u16;
+                    let top = area.top() + (area.height - len) / 2;
+                    for (i, ch) in label.chars().enumerate() {
+                        if let Some(cell) = buf.cell_mut((col_for(center), top + i as u16)) {
+                            cell.set_char(ch).set_style(style);
+                        }
+                    }
+                }
+                _ => {
+                    let mid_y = area.top() + area.height / 2;
+                    let len = team.abbreviation.len() as u16;
+                    let x = if center < 60.0 { area.left() } else { area.right().saturating_sub(len) };
+                    buf.set_string(x, mid_y, &team.abbreviation, style);
+                }
+            }
         }
 
+        // With no ball to place, say why: before the kickoff, at the half, between quarters, or after the final
+        let spotted = self.comp.situation.as_ref().is_some_and(|s| s.yard_line.is_some());
+        if !spotted {
+            let status = &self.status.type_field;
+            let banner = match (&status.state, status.description.as_str()) {
+                (GameState::Post, _) => status.short_detail.to_uppercase(),
+                (GameState::In, "Halftime") => "HALFTIME".to_string(),
+                (GameState::In, "End of Period") => status.short_detail.to_uppercase(),
+                _ => "KICKOFF".to_string(),
+            };
+            let score = |c: &crate::model::Competitor| format!("{} {}", c.team.abbreviation, c.score.as_deref().unwrap_or("0"));
+            let mut lines = vec![format!(" {} ", banner)];
+            if area.height >= 3 && status.state != GameState::Pre {
+                lines.push(format!(" {}  {} ", score(self.away), score(self.home)));
+            }
+            let style = Style::default().fg(Color::White).bg(Color::Black).add_modifier(Modifier::BOLD);
+            let top = area.top() + (area.height - lines.len() as u16) / 2;
+            for (i, line) in lines.iter().enumerate() {
+                let width = line.chars().count() as u16;
+                let x = area.left() + area.width.saturating_sub(width) / 2;
+                buf.set_string(x, top + i as u16, line, style);
+            }
+        }
     }
 }
@@ -1,46 +1,351 @@
-use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+// How far down the poll the leading team has to be for a ranked-vs-ranked game to be an upset
+pub const UPSET_RANK_GAP: u32 = 10;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct ScoreboardResponse {
+    #[serde(deserialize_with = "skip_bad_events")]
     pub events: Vec<Event>,
+    pub week: Option<Week>,
+    pub season: Option<Season>,
+}
+
+// A game that doesn't parse is left out (and logged) rather than failing the whole scoreboard
+fn skip_bad_events<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Event>, D::Error> {
+    let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+    let events = values
+        .into_iter()
+        .filter_map(|value| {
+            let id = value.get("id").and_then(|id| id.as_str()).unwrap_or("?").to_string();
+            Event::deserialize(value)
+                .inspect_err(|err| tracing::warn!(event = %id, %err, "skipping an event that didn't parse"))
+                .ok()
+        })
+        .collect();
+    Ok(events)
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+pub struct Week {
+    pub number: u32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Season {
+    pub year: i32,
+    #[serde(rename = "type")]
+    pub type_field: i32,
+    pub slug: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Event {
+    pub id: String,
+    pub date: Option<String>,
     pub short_name: String,
     pub competitions: Vec<Competition>,
     pub status: Status,
+    pub season: Option<Season>,
+    pub week: Option<Week>,
+    pub weather: Option<Weather>, // forecast before kickoff; left off for domes
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+pub struct Weather {
+    pub temperature: Option<f64>,
+    pub high_temperature: Option<f64>,
+    pub display_value: Option<String>,
+    pub condition_id: Option<String>, // e.g. "Clear"
+}
+
+impl Weather {
+    /// "37°F, Clear", or whichever half ESPN sent.
+    pub fn summary(&self) -> Option<String> {
+        let temperature = self.temperature.map(|t| format!("{}°F", t));
+        let parts: Vec<String> = temperature.into_iter().chain(self.condition_id.clone()).collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+impl Event {
+    /// Kickoff time. ESPN leaves the seconds off ("2025-12-20T17:00Z"), which RFC 3339 requires.
+    pub fn kickoff(&self) -> Option<DateTime<Utc>> {
+        let date = self.date.as_deref()?;
+        DateTime::parse_from_rfc3339(date)
+            .map(|d| d.with_timezone(&Utc))
+            .or_else(|_| NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%MZ").map(|d| d.and_utc()))
+            .ok()
+    }
+
+    /// The first note ESPN attaches to the game, e.g. "Rose Bowl" or "CFP Semifinal".
+    pub fn note(&self) -> Option<&str> {
+        self.competitions.first()?.notes.first().map(|n| n.headline.as_str())
+    }
+
+    /// A line of context for the game panel: the note if there is one ("NFC Wild Card
+    /// Playoffs"), otherwise the recap's headline.
+    pub fn subtitle(&self) -> Option<&str> {
+        self.note().or_else(|| self.competitions.first()?.headlines.iter().find_map(|h| h.short_link_text.as_deref()))
+    }
+
+    /// A ranked team trailing an unranked one, or one at least `UPSET_RANK_GAP` spots
+    /// lower, in the second half.
+    pub fn upset_watch(&self) -> bool {
+        if self.status.type_field.state != GameState::In || self.status.period < 3 {
+            return false;
+        }
+        let Some([a, b]) = self.competitions.first().map(|c| c.competitors.as_slice()) else {
+            return false;
+        };
+        let score = |c: &Competitor| c.score.as_deref().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0);
+        let (leader, trailer) = match score(a).cmp(&score(b)) {
+            Ordering::Greater => (a, b),
+            Ordering::Less => (b, a),
+            Ordering::Equal => return false,
+        };
+        match (trailer.rank(), leader.rank()) {
+            (Some(_), None) => true,
+            (Some(favorite), Some(underdog)) => underdog >= favorite + UPSET_RANK_GAP,
+            _ => false,
+        }
+    }
+
+    /// College Football Playoff games, as opposed to the rest of the bowls.
+    pub fn is_playoff(&self) -> bool {
+        self.note().is_some_and(|n| n.contains("College Football Playoff") || n.contains("CFP"))
+    }
+
+    /// Whether the letters of `query` appear in order in either team's name or abbreviation,
+    /// ignoring case, so "lar", "rams" and "lsa" all find the Rams.
+    pub fn fuzzy_matches(&self, query: &str) -> bool {
+        let query: Vec<char> = query.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+        let names = self.competitions.iter().flat_map(|c| &c.competitors).flat_map(|c| {
+            [&c.team.abbreviation, &c.team.display_name, &c.team.short_display_name]
+        });
+        names.into_iter().any(|name| {
+            let mut remaining = query.iter().peekable();
+            for c in name.to_lowercase().chars() {
+                if remaining.peek() == Some(&&c) {
+                    remaining.next();
+                }
+            }
+            remaining.peek().is_none()
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Competition {
     pub competitors: Vec<Competitor>,
     pub status: Status,
     pub situation: Option<Situation>,
     pub broadcasts: Option<Vec<Broadcast>>,
+    pub odds: Option<Vec<Odds>>,
+    pub leaders: Option<Vec<LeaderCategory>>,
+    pub notes: Vec<Note>,
+    pub headlines: Vec<Headline>,
+    pub geo_broadcasts: Vec<GeoBroadcast>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+pub struct Note {
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub headline: String,
+}
+
+/// ESPN's write-up of a game, once there is one (usually the recap after the final).
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Headline {
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub description: Option<String>,
+    pub short_link_text: Option<String>, // the headline itself
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LeaderCategory {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_display_name: Option<String>, // "PASS", "RUSH", "REC"
+    pub leaders: Vec<Leader>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Leader {
+    pub display_value: String, // e.g. "19/37, 307 YDS, 2 TD"
+    pub athlete: Option<Athlete>,
+    pub team: Option<TeamRef>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Odds {
+    pub provider: Option<OddsProvider>,
+    pub details: Option<String>, // e.g. "KC -3.5"
+    pub over_under: Option<f64>,
+    pub spread: Option<f64>,
+    pub away_team_odds: Option<TeamOdds>,
+    pub home_team_odds: Option<TeamOdds>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OddsProvider {
+    pub name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TeamOdds {
+    pub favorite: bool,
+    pub money_line: Option<f64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Broadcast {
     pub market: Option<String>,
     pub names: Vec<String>,
 }
 
+/// One channel carrying the game, with enough detail to tell TV from streaming and
+/// national from local coverage (which `broadcasts` only gives as a bare list of names).
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+pub struct GeoBroadcast {
+    #[serde(rename = "type")]
+    pub type_field: Option<BroadcastType>,
+    pub market: Option<BroadcastMarket>,
+    pub media: Option<BroadcastMedia>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BroadcastType {
+    pub short_name: Option<String>, // "TV", "Streaming", "Radio"
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BroadcastMarket {
+    #[serde(rename = "type")]
+    pub type_field: Option<String>, // "National", "Home", "Away"
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct BroadcastMedia {
+    pub short_name: Option<String>,
+}
+
+/// How a channel reaches viewers, as far as the status bar cares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coverage {
+    National,
+    Local,
+    Streaming,
+}
+
+impl GeoBroadcast {
+    pub fn name(&self) -> Option<&str> {
+        self.media.as_ref()?.short_name.as_deref()
+    }
+
+    /// `None` for radio and anything else that isn't something to watch.
+    pub fn coverage(&self) -> Option<Coverage> {
+        let kind = self.type_field.as_ref().and_then(|t| t.short_name.as_deref());
+        let national = self.market.as_ref().and_then(|m| m.type_field.as_deref()).is_none_or(|m| m == "National");
+        match kind {
+            Some("Streaming") => Some(Coverage::Streaming),
+            Some("TV") | None if national => Some(Coverage::National),
+            Some("TV") | None => Some(Coverage::Local),
+            Some(_) => None,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Competitor {
     pub team: Team,
     pub score: Option<String>,
     pub home_away: String,
     pub winner: Option<bool>,
+    pub linescores: Option<Vec<Linescore>>,
+    pub records: Vec<Record>,
+    // College football only
+    pub curated_rank: Option<CuratedRank>,
+}
+
+impl Competitor {
+    /// Poll ranking for college teams; ESPN marks unranked teams as 99.
+    pub fn rank(&self) -> Option<u32> {
+        self.curated_rank.as_ref().and_then(|r| r.current).filter(|r| (1..=25).contains(r))
+    }
+
+    /// "#3 UGA", or just "UGA" when unranked.
+    pub fn label(&self) -> String {
+        match self.rank() {
+            Some(rank) => format!("#{} {}", rank, self.team.abbreviation),
+            None => self.team.abbreviation.clone(),
+        }
+    }
+
+    /// Overall season record, e.g. "9-2".
+    pub fn record(&self) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| r.type_field.as_deref() == Some("total"))
+            .or_else(|| self.records.first())
+            .map(|r| r.summary.as_str())
+    }
+
+    /// Record at home or on the road (ESPN calls away games "road").
+    pub fn venue_record(&self, home: bool) -> Option<&str> {
+        let kind = if home { "home" } else { "road" };
+        self.records.iter().find(|r| r.type_field.as_deref() == Some(kind)).map(|r| r.summary.as_str())
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
+pub struct Record {
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub summary: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CuratedRank {
+    pub current: Option<u32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Linescore {
+    pub value: f64,
+    pub period: Option<i32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Team {
     pub id: Option<String>,
     pub abbreviation: String,
@@ -52,7 +357,7 @@ pub struct Team {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct Status {
     pub period: i32,
     pub display_clock: String,
@@ -61,17 +366,68 @@ pub struct Status {
     pub type_field: StatusType,
 }
 
+impl Status {
+    /// Past the four quarters of regulation.
+    pub fn is_overtime(&self) -> bool {
+        self.period > 4
+    }
+
+    /// "Q3", "OT", or "2OT" for the second overtime (college games can go several).
+    pub fn period_label(&self) -> String {
+        match self.period {
+            5 => "OT".to_string(),
+            p if p > 5 => format!("{}OT", p - 4),
+            p => format!("Q{}", p),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct StatusType {
-    pub state: String, // "pre", "in", "post"
+    pub state: GameState,
     pub short_detail: String,
     pub description: String,
     pub detail: String,
 }
 
+/// ESPN's `status.type.state`. Delays and postponements come through as one of
+/// these too, with the details in the status description.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum GameState {
+    #[default]
+    Pre,
+    In,
+    Post,
+    /// Anything else ESPN sends, kept as is.
+    Other(String),
+}
+
+impl From<String> for GameState {
+    fn from(state: String) -> Self {
+        match state.as_str() {
+            "pre" => GameState::Pre,
+            "in" => GameState::In,
+            "post" => GameState::Post,
+            _ => GameState::Other(state),
+        }
+    }
+}
+
+impl From<GameState> for String {
+    fn from(state: GameState) -> Self {
+        match state {
+            GameState::Pre => "pre".to_string(),
+            GameState::In => "in".to_string(),
+            GameState::Post => "post".to_string(),
+            GameState::Other(state) => state,
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct Situation {
     pub down: Option<i32>,
     pub distance: Option<i32>,
@@ -79,10 +435,586 @@ pub struct Situation {
     pub short_down_distance_text: Option<String>,
     pub possession: Option<String>,
     pub last_play: Option<LastPlay>,
+    pub is_red_zone: Option<bool>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 pub struct LastPlay {
+    pub id: Option<String>,
+    pub text: String,
+    #[serde(rename = "type")]
+    pub type_field: Option<PlayType>,
+    pub probability: Option<Probability>,
+}
+
+impl LastPlay {
+    /// Interceptions and fumbles lost, going by ESPN's play type.
+    pub fn is_turnover(&self) -> bool {
+        let kind = self.type_field.as_ref().and_then(|t| t.text.as_deref()).unwrap_or_default();
+        kind.contains("Interception") || kind.contains("(Opponent)")
+    }
+
+    /// The flag from the play text, e.g. "PENALTY on ATL-D.Bates, False Start, 5 yards".
+    pub fn penalty(&self) -> Option<&str> {
+        let rest = &self.text[self.text.find("PENALTY")?..];
+        let end = rest.find(" enforced").or_else(|| rest.find(". ")).unwrap_or(rest.len());
+        Some(rest[..end].trim_end_matches([',', '.', ' ']))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PlayType {
+    pub text: Option<String>,
+    pub abbreviation: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Probability {
+    pub home_win_percentage: f64,
+    pub away_win_percentage: Option<f64>,
+    pub tie_percentage: f64,
+}
+
+impl Probability {
+    /// (away, home) win chances in 0.0..=1.0.
+    pub fn split(&self) -> (f64, f64) {
+        let away = self
+            .away_win_percentage
+            .unwrap_or(1.0 - self.home_win_percentage - self.tie_percentage);
+        (away, self.home_win_percentage)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    pub drives: Option<Drives>,
+    pub boxscore: Option<Boxscore>,
+    // One entry per play, oldest first
+    #[serde(default)]
+    pub winprobability: Vec<Probability>,
+    #[serde(default)]
+    pub scoring_plays: Vec<ScoringPlay>,
+    // The recap after a game, or the preview before it
+    pub article: Option<Article>,
+    pub game_info: Option<GameInfo>,
+    #[serde(default)]
+    pub injuries: Vec<TeamInjuries>,
+    // This season's earlier meetings (division rivals play twice)
+    #[serde(default, rename = "seasonseries")]
+    pub season_series: Vec<SeasonSeries>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameInfo {
+    pub venue: Option<Venue>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub full_name: String,
+    pub address: Option<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    pub city: Option<String>,
+    pub state: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamInjuries {
+    pub team: TeamRef,
+    #[serde(default)]
+    pub injuries: Vec<Injury>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Injury {
+    pub status: Option<String>, // "Out", "Doubtful", "Questionable"
+    pub athlete: Option<Athlete>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonSeries {
+    pub title: Option<String>,
+    pub summary: Option<String>, // "ATL leads series 1-0"
+    #[serde(default)]
+    pub events: Vec<SeriesEvent>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesEvent {
+    pub id: Option<String>,
+    pub date: Option<String>,
+    #[serde(default)]
+    pub competitors: Vec<SeriesCompetitor>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesCompetitor {
+    pub team: TeamRef,
+    pub score: Option<String>,
+    pub winner: Option<bool>,
+    pub home_away: Option<String>,
+}
+
+// A team's season schedule, from the teams/{id}/schedule endpoint
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schedule {
+    #[serde(default)]
+    pub events: Vec<ScheduleEvent>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleEvent {
+    pub id: Option<String>,
+    pub date: Option<String>,
+    #[serde(default)]
+    pub competitions: Vec<ScheduleCompetition>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleCompetition {
+    #[serde(default)]
+    pub competitors: Vec<ScheduleCompetitor>,
+}
+
+// Same as a scoreboard competitor, except the score is an object here
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleCompetitor {
+    pub team: TeamRef,
+    pub score: Option<ScheduleScore>,
+    pub winner: Option<bool>,
+    pub home_away: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleScore {
+    pub display_value: String,
+}
+
+impl ScheduleEvent {
+    /// Played games against `opponent_id`, in the same shape as this season's series.
+    pub fn meeting(&self, opponent_id: &str) -> Option<SeriesEvent> {
+        let competitors = &self.competitions.first()?.competitors;
+        let played = competitors.iter().all(|c| c.score.is_some()) && competitors.iter().any(|c| c.winner.is_some());
+        let against = competitors.iter().any(|c| c.team.id.as_deref() == Some(opponent_id));
+        (played && against).then(|| SeriesEvent {
+            id: self.id.clone(),
+            date: self.date.clone(),
+            competitors: competitors
+                .iter()
+                .map(|c| SeriesCompetitor {
+                    team: c.team.clone(),
+                    score: c.score.as_ref().map(|s| s.display_value.clone()),
+                    winner: c.winner,
+                    home_away: c.home_away.clone(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringPlay {
+    pub id: Option<String>,
+    #[serde(default)]
     pub text: String,
+    pub period: Option<Period>,
+    pub clock: Option<Clock>,
+    pub team: Option<TeamRef>,
+    pub scoring_type: Option<ScoringType>,
+    pub away_score: Option<i32>,
+    pub home_score: Option<i32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringType {
+    pub display_name: Option<String>,
+    pub abbreviation: Option<String>, // "TD", "FG", "SF"
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Boxscore {
+    #[serde(default)]
+    pub teams: Vec<BoxscoreTeam>,
+    #[serde(default)]
+    pub players: Vec<BoxscorePlayers>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscoreTeam {
+    pub team: TeamRef,
+    pub home_away: Option<String>,
+    #[serde(default)]
+    pub statistics: Vec<TeamStat>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamStat {
+    pub name: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub display_value: String,
+}
+
+impl BoxscoreTeam {
+    /// A team total by ESPN's stat name, e.g. "totalYards" -> "341".
+    pub fn stat(&self, name: &str) -> Option<&str> {
+        self.statistics.iter().find(|s| s.name == name).map(|s| s.display_value.as_str())
+    }
+
+    /// Time of possession in seconds, from "possessionTime" ("31:24").
+    pub fn possession_secs(&self) -> Option<u32> {
+        let (minutes, seconds) = self.stat("possessionTime")?.split_once(':')?;
+        Some(minutes.parse::<u32>().ok()? * 60 + seconds.parse::<u32>().ok()?)
+    }
+
+    /// Made and attempted from a "made-att" stat such as "thirdDownEff" ("4-11").
+    pub fn conversions(&self, name: &str) -> Option<(u32, u32)> {
+        let (made, attempts) = self.stat(name)?.split_once('-')?;
+        Some((made.trim().parse().ok()?, attempts.trim().parse().ok()?))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxscorePlayers {
+    pub team: TeamRef,
+    #[serde(default)]
+    pub statistics: Vec<StatCategory>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatCategory {
+    pub name: String,
+    pub text: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub athletes: Vec<AthleteStats>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthleteStats {
+    pub athlete: Athlete,
+    #[serde(default)]
+    pub stats: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Athlete {
+    pub display_name: String,
+    pub short_name: Option<String>,
+    pub position: Option<Position>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Position {
+    pub abbreviation: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Drives {
+    #[serde(default)]
+    pub previous: Vec<Drive>,
+    pub current: Option<Drive>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Drive {
+    pub id: Option<String>,
+    pub description: Option<String>,
+    pub team: Option<TeamRef>,
+    pub display_result: Option<String>,
+    pub start: Option<DrivePosition>,
+    pub end: Option<DrivePosition>,
+    pub yards: Option<i32>,
+    pub offensive_plays: Option<i32>,
+    #[serde(default)]
+    pub is_score: bool,
+    pub time_elapsed: Option<Clock>,
+    #[serde(default)]
+    pub plays: Vec<Play>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DrivePosition {
+    // Yards to the opponent's end zone from the offense's point of view
+    pub yard_line: Option<i32>,
+    pub text: Option<String>,
+}
+
+// Summary payloads reference teams with far fewer fields than the scoreboard
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TeamRef {
+    pub id: Option<String>,
+    pub abbreviation: Option<String>,
+    pub display_name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Play {
+    pub id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    pub period: Option<Period>,
+    pub clock: Option<Clock>,
+    #[serde(default)]
+    pub scoring_play: bool,
+    pub start: Option<PlayStart>,
+    pub away_score: Option<i32>,
+    pub home_score: Option<i32>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Period {
+    pub number: i32,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Clock {
+    pub display_value: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayStart {
+    pub down: Option<i32>,
+    pub distance: Option<i32>,
+    pub short_down_distance_text: Option<String>,
+    pub possession_text: Option<String>,
+}
+
+impl Summary {
+    /// Completed drives followed by the in-progress one (ESPN sometimes repeats it in `previous`).
+    pub fn drives(&self) -> Vec<&Drive> {
+        let Some(drives) = &self.drives else {
+            return Vec::new();
+        };
+        let mut all: Vec<&Drive> = drives.previous.iter().collect();
+        if let Some(current) = &drives.current {
+            if !all.iter().any(|d| d.id.is_some() && d.id == current.id) {
+                all.push(current);
+            }
+        }
+        all
+    }
+
+    /// Every score in order. ESPN usually sends `scoringPlays`; when it doesn't, they're
+    /// picked out of the drives.
+    pub fn scoring_plays(&self) -> Vec<ScoringPlay> {
+        if !self.scoring_plays.is_empty() {
+            return self.scoring_plays.clone();
+        }
+        self.drives()
+            .into_iter()
+            .flat_map(|drive| {
+                drive.plays.iter().filter(|p| p.scoring_play).map(|play| ScoringPlay {
+                    id: play.id.clone(),
+                    text: play.text.clone(),
+                    period: play.period.clone(),
+                    clock: play.clock.clone(),
+                    team: drive.team.clone(),
+                    scoring_type: None,
+                    away_score: play.away_score,
+                    home_score: play.home_score,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Standings {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub children: Vec<StandingsGroup>,
+}
+
+// Conferences nest divisions; only the leaves carry a standings table
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsGroup {
+    pub name: String,
+    pub abbreviation: Option<String>,
+    #[serde(default)]
+    pub children: Vec<StandingsGroup>,
+    pub standings: Option<StandingsTable>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsTable {
+    #[serde(default)]
+    pub entries: Vec<StandingsEntry>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsEntry {
+    pub team: TeamRef,
+    #[serde(default)]
+    pub stats: Vec<StandingsStat>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandingsStat {
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub value: Option<f64>,
+    pub display_value: Option<String>,
+    pub summary: Option<String>,
+}
+
+fn collect_leaves<'a>(groups: &'a [StandingsGroup], out: &mut Vec<&'a StandingsGroup>) {
+    for group in groups {
+        if group.standings.is_some() {
+            out.push(group);
+        }
+        collect_leaves(&group.children, out);
+    }
+}
+
+impl Standings {
+    /// Leaf groups (divisions, or conferences when ESPN doesn't split further) in display order.
+    pub fn groups(&self) -> Vec<&StandingsGroup> {
+        let mut out = vec![];
+        collect_leaves(&self.children, &mut out);
+        out
+    }
+
+    /// Each team's conference ("AFC"/"NFC") and playoff seed, by abbreviation.
+    pub fn playoff_seeds(&self) -> HashMap<String, (String, u32)> {
+        let mut seeds = HashMap::new();
+        for conference in &self.children {
+            let name = conference.abbreviation.clone().unwrap_or_else(|| conference.name.clone());
+            let mut divisions = vec![];
+            collect_leaves(std::slice::from_ref(conference), &mut divisions);
+            for entry in divisions.iter().flat_map(|d| d.standings.iter().flat_map(|s| &s.entries)) {
+                let seed = entry.stat("playoffSeed").and_then(|s| s.parse().ok());
+                if let (Some(abbreviation), Some(seed)) = (&entry.team.abbreviation, seed) {
+                    seeds.insert(abbreviation.clone(), (name.clone(), seed));
+                }
+            }
+        }
+        seeds
+    }
+}
+
+impl StandingsEntry {
+    /// Display value for a stat, matched on either its `name` or `type` (ESPN is inconsistent).
+    pub fn stat(&self, key: &str) -> Option<&str> {
+        self.stats
+            .iter()
+            .find(|s| s.name.as_deref() == Some(key) || s.type_field.as_deref() == Some(key))
+            .and_then(|s| s.display_value.as_deref().or(s.summary.as_deref()))
+    }
+}
+
+// College football polls (AP, Coaches, CFP, ...)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rankings {
+    #[serde(default)]
+    pub rankings: Vec<Poll>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Poll {
+    pub name: String,
+    pub short_name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    #[serde(default)]
+    pub ranks: Vec<Rank>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rank {
+    pub current: u32,
+    pub previous: Option<u32>,
+    pub points: Option<f64>,
+    pub first_place_votes: Option<u32>,
+    pub record_summary: Option<String>,
+    pub team: RankedTeam,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankedTeam {
+    pub id: Option<String>,
+    pub abbreviation: Option<String>,
+    pub location: Option<String>,
+    pub name: Option<String>,
+}
+
+impl Rankings {
+    /// The AP and Coaches polls when ESPN has them, otherwise whatever polls it sent.
+    pub fn main_polls(&self) -> Vec<&Poll> {
+        let main: Vec<&Poll> = self
+            .rankings
+            .iter()
+            .filter(|p| matches!(p.type_field.as_deref(), Some("ap") | Some("usa")))
+            .collect();
+        if main.is_empty() {
+            self.rankings.iter().take(2).collect()
+        } else {
+            main
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct News {
+    #[serde(default)]
+    pub articles: Vec<Article>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+    pub headline: String,
+    pub description: Option<String>,
+    pub published: Option<String>,
+    pub byline: Option<String>,
 }
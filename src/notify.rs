@@ -0,0 +1,673 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::Write;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::task::JoinSet;
+use tracing::{info, warn};
+
+use crate::headless::score_line;
+use crate::data::ScoreboardSource;
+use crate::model::{Event, GameState, LastPlay, ScoringPlay, Summary};
+
+/// Changes in a game worth telling someone about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    GameStart,
+    ScoreChange,
+    Turnover,
+    Final,
+    CloseGame,
+    UpsetWatch,
+    KickoffSoon,
+    Overtime,
+}
+
+impl AlertKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            AlertKind::GameStart => "Kickoff",
+            AlertKind::ScoreChange => "Score",
+            AlertKind::Turnover => "Turnover",
+            AlertKind::Final => "Final",
+            AlertKind::CloseGame => "Close game",
+            AlertKind::UpsetWatch => "Upset watch",
+            AlertKind::KickoffSoon => "Kickoff soon",
+            AlertKind::Overtime => "OT!",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub event: Event,
+    /// What happened, when there's more to say than the score (the scoring plays).
+    pub detail: Option<String>,
+}
+
+impl Alert {
+    fn new(kind: AlertKind, event: &Event) -> Self {
+        Self { kind, event: event.clone(), detail: None }
+    }
+
+    /// "Score: LAR 7 @ ATL 3  4:11 - 2nd", or with the play spelled out instead of
+    /// "Score": "TD LAR: K.Williams 23 Yd pass from M.Stafford ...  LAR 7 @ ATL 3  4:11 - 2nd"
+    pub fn message(&self) -> String {
+        match &self.detail {
+            Some(detail) => format!("{}  {}", detail, score_line(&self.event)),
+            None => format!("{}: {}", self.kind.label(), score_line(&self.event)),
+        }
+    }
+}
+
+fn all_kinds() -> Vec<AlertKind> {
+    vec![AlertKind::GameStart, AlertKind::ScoreChange, AlertKind::Overtime, AlertKind::Final]
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default = "all_kinds")]
+    pub events: Vec<AlertKind>,
+}
+
+fn default_hook_kinds() -> Vec<AlertKind> {
+    vec![AlertKind::ScoreChange, AlertKind::Turnover, AlertKind::Overtime, AlertKind::Final]
+}
+
+/// A shell command to run on each alert, with the details in `NFL_*` environment variables.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HookConfig {
+    pub command: String,
+    #[serde(default = "default_hook_kinds")]
+    pub events: Vec<AlertKind>,
+}
+
+fn default_ntfy_server() -> String {
+    "https://ntfy.sh".to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NtfyConfig {
+    pub topic: String,
+    #[serde(default = "default_ntfy_server")]
+    pub server: String,
+    #[serde(default = "all_kinds")]
+    pub events: Vec<AlertKind>,
+}
+
+/// When a game counts as close: within `margin` points with `minutes` or less left in
+/// the fourth quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct CloseGameConfig {
+    pub minutes: u32,
+    pub margin: u32,
+}
+
+impl Default for CloseGameConfig {
+    fn default() -> Self {
+        // One score, with a two-point conversion
+        Self { minutes: 5, margin: 8 }
+    }
+}
+
+impl CloseGameConfig {
+    pub fn is_close(&self, event: &Event) -> bool {
+        if event.status.type_field.state != GameState::In || event.status.period != 4 {
+            return false;
+        }
+        let Some(clock) = event.status.clock else {
+            return false;
+        };
+        let Some(comp) = event.competitions.first() else {
+            return false;
+        };
+        let mut scores = comp.competitors.iter().map(|c| c.score.as_deref().and_then(|s| s.parse::<u32>().ok()).unwrap_or(0));
+        let (Some(a), Some(b)) = (scores.next(), scores.next()) else {
+            return false;
+        };
+        clock <= (self.minutes * 60) as f64 && a.abs_diff(b) <= self.margin
+    }
+}
+
+fn default_sound_kinds() -> Vec<AlertKind> {
+    vec![AlertKind::ScoreChange, AlertKind::Final]
+}
+
+/// Audible alerts in the TUI for favorite teams' games: the terminal bell, or a
+/// command that plays a sound instead.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SoundConfig {
+    pub command: Option<String>,
+    #[serde(default = "default_sound_kinds")]
+    pub events: Vec<AlertKind>,
+}
+
+impl SoundConfig {
+    /// Rings the bell or starts the sound command, without waiting for it to finish.
+    pub fn play(&self) -> Result<()> {
+        let Some(command) = &self.command else {
+            let mut stdout = std::io::stdout();
+            stdout.write_all(b"\x07")?;
+            stdout.flush()?;
+            return Ok(());
+        };
+        let mut child = shell(command).stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).spawn()?;
+        tokio::spawn(async move {
+            let _ = child.wait().await;
+        });
+        Ok(())
+    }
+}
+
+// `sh -c`, or `cmd /C` on Windows
+fn shell(command: &str) -> tokio::process::Command {
+    let mut c = if cfg!(windows) {
+        let mut c = tokio::process::Command::new("cmd");
+        c.arg("/C");
+        c
+    } else {
+        let mut c = tokio::process::Command::new("sh");
+        c.arg("-c");
+        c
+    };
+    c.arg(command);
+    c
+}
+
+/// Compares two scoreboard snapshots. Games missing from `prev` are skipped so the
+/// first fetch (or a week change) doesn't fire a burst of alerts.
+pub fn detect(prev: &HashMap<String, Event>, next: &[Event]) -> Vec<Alert> {
+    let mut alerts = vec![];
+    for event in next {
+        let Some(old) = prev.get(&event.id) else {
+            continue;
+        };
+        let (was, now) = (&old.status.type_field.state, &event.status.type_field.state);
+        let scores = |e: &Event| -> Vec<Option<String>> {
+            e.competitions.iter().flat_map(|c| &c.competitors).map(|c| c.score.clone()).collect()
+        };
+
+        let last_play = |e: &Event| -> Option<LastPlay> {
+            e.competitions.first()?.situation.as_ref()?.last_play.clone()
+        };
+
+        let mut kinds = vec![];
+        if *was == GameState::Pre && *now == GameState::In {
+            kinds.push(AlertKind::GameStart);
+        } else if *was != GameState::Post && *now == GameState::Post {
+            kinds.push(AlertKind::Final);
+        } else if *now == GameState::In {
+            if scores(old) != scores(event) {
+                kinds.push(AlertKind::ScoreChange);
+            }
+            if event.upset_watch() && !old.upset_watch() {
+                kinds.push(AlertKind::UpsetWatch);
+            }
+            if event.status.is_overtime() && !old.status.is_overtime() {
+                kinds.push(AlertKind::Overtime);
+            }
+            // A pick-six is both a score and a turnover
+            if let Some(play) = last_play(event) {
+                let is_new = last_play(old).map(|p| p.id) != Some(play.id.clone());
+                if is_new && play.is_turnover() {
+                    kinds.push(AlertKind::Turnover);
+                }
+            }
+        }
+        alerts.extend(kinds.into_iter().map(|kind| Alert::new(kind, event)));
+    }
+    alerts
+}
+
+/// Games that have just turned close, by `close`'s thresholds. Like `detect`, games
+/// missing from `prev` are skipped.
+pub fn detect_close_games(prev: &HashMap<String, Event>, next: &[Event], close: &CloseGameConfig) -> Vec<Alert> {
+    next.iter()
+        .filter(|event| prev.get(&event.id).is_some_and(|old| !close.is_close(old)) && close.is_close(event))
+        .map(|event| Alert::new(AlertKind::CloseGame, event))
+        .collect()
+}
+
+/// Games kicking off within `lead` of `now`, each only once: `reminded` keeps the ids
+/// already announced.
+pub fn detect_kickoffs_soon(events: &[Event], lead: Duration, now: DateTime<Utc>, reminded: &mut HashSet<String>) -> Vec<Alert> {
+    events
+        .iter()
+        .filter(|event| event.status.type_field.state == GameState::Pre)
+        .filter(|event| event.kickoff().is_some_and(|kickoff| kickoff > now && kickoff - now <= lead))
+        .filter(|event| reminded.insert(event.id.clone()))
+        .map(|event| Alert::new(AlertKind::KickoffSoon, event))
+        .collect()
+}
+
+// What a scoring play is remembered by; plays picked out of the drives may not have an id
+fn play_key(play: &ScoringPlay) -> String {
+    play.id.clone().unwrap_or_else(|| play.text.clone())
+}
+
+/// The scoring plays not in `seen`. The first time a game is looked at there's nothing
+/// seen yet, so it's the plays that took the combined score past `previous_total`.
+pub fn new_scoring_plays<'a>(seen: Option<&HashSet<String>>, plays: &'a [ScoringPlay], previous_total: i32) -> Vec<&'a ScoringPlay> {
+    plays
+        .iter()
+        .filter(|play| match seen {
+            Some(seen) => !seen.contains(&play_key(play)),
+            None => play.away_score.unwrap_or(0) + play.home_score.unwrap_or(0) > previous_total,
+        })
+        .collect()
+}
+
+/// "TD LAR: K.Williams 23 Yd pass from M.Stafford (J.Karty Kick)", with whichever of the
+/// type and team ESPN gave.
+pub fn scoring_detail(play: &ScoringPlay) -> String {
+    let kind = play.scoring_type.as_ref().and_then(|t| t.abbreviation.as_deref().or(t.display_name.as_deref()));
+    let team = play.team.as_ref().and_then(|t| t.abbreviation.as_deref());
+    let label = kind.into_iter().chain(team).collect::<Vec<_>>().join(" ");
+    if label.is_empty() {
+        play.text.clone()
+    } else {
+        format!("{}: {}", label, play.text)
+    }
+}
+
+fn total_score(event: &Event) -> i32 {
+    event.competitions.iter().flat_map(|c| &c.competitors).filter_map(|c| c.score.as_deref()?.parse::<i32>().ok()).sum()
+}
+
+/// Watches scoreboard refreshes from the background fetcher and sends alerts for
+/// favorite teams (or every game, when there are no favorites).
+pub struct Notifier {
+    client: Client,
+    webhook: Option<WebhookConfig>,
+    ntfy: Option<NtfyConfig>,
+    hook: Option<HookConfig>,
+    // Shared with the UI, so starring a team takes effect right away
+    teams: Arc<Mutex<BTreeSet<String>>>,
+    close_game: CloseGameConfig,
+    kickoff_reminder: Option<Duration>,
+    last: HashMap<String, Event>,
+    reminded: HashSet<String>,
+    // The scoring plays already announced for each game
+    scoring: HashMap<String, HashSet<String>>,
+}
+
+impl Notifier {
+    pub fn new(
+        webhook: Option<WebhookConfig>,
+        ntfy: Option<NtfyConfig>,
+        hook: Option<HookConfig>,
+        teams: Arc<Mutex<BTreeSet<String>>>,
+        close_game: CloseGameConfig,
+        kickoff_reminder: Option<Duration>,
+        client: Client,
+    ) -> Self {
+        Self {
+            client,
+            webhook,
+            ntfy,
+            hook,
+            teams,
+            close_game,
+            kickoff_reminder,
+            last: HashMap::new(),
+            reminded: HashSet::new(),
+            scoring: HashMap::new(),
+        }
+    }
+
+    fn wants(&self, event: &Event) -> bool {
+        let teams = self.teams.lock().unwrap();
+        teams.is_empty()
+            || event
+                .competitions
+                .iter()
+                .flat_map(|c| &c.competitors)
+                .any(|c| teams.contains(&c.team.abbreviation))
+    }
+
+    /// Sends the alerts for a new scoreboard. Score changes in followed games, and in
+    /// `showing` (the game on screen), are spelled out from the game summary's scoring plays;
+    /// the summaries fetched for that are returned, each with the plays it spelled out, so
+    /// the UI can use them too.
+    pub async fn observe<S: ScoreboardSource + 'static>(
+        &mut self,
+        source: &Arc<S>,
+        league: &str,
+        events: &[Event],
+        showing: Option<&str>,
+    ) -> Vec<(String, Summary, Option<String>)> {
+        let mut alerts = detect(&self.last, events);
+        alerts.extend(detect_close_games(&self.last, events, &self.close_game));
+        if let Some(lead) = self.kickoff_reminder {
+            alerts.extend(detect_kickoffs_soon(events, lead, Utc::now(), &mut self.reminded));
+        }
+
+        // All at once, so a busy slate costs one round trip rather than one per game
+        let mut fetches = JoinSet::new();
+        for alert in alerts.iter().filter(|a| a.kind == AlertKind::ScoreChange) {
+            if !self.wants(&alert.event) && showing != Some(alert.event.id.as_str()) {
+                continue;
+            }
+            let (source, league, id) = (source.clone(), league.to_string(), alert.event.id.clone());
+            fetches.spawn(async move {
+                let summary = source.fetch_summary(&league, &id).await;
+                (id, summary)
+            });
+        }
+
+        let mut summaries = vec![];
+        while let Some(joined) = fetches.join_next().await {
+            let Ok((id, summary)) = joined else { continue };
+            // Without the summary the alert still goes out, with just the score
+            let summary = match summary {
+                Ok(summary) => summary,
+                Err(err) => {
+                    warn!(event_id = %id, %err, "couldn't fetch scoring plays");
+                    continue;
+                }
+            };
+            let plays = summary.scoring_plays();
+            let previous_total = self.last.get(&id).map(total_score).unwrap_or_default();
+            let details: Vec<String> = new_scoring_plays(self.scoring.get(&id), &plays, previous_total).into_iter().map(scoring_detail).collect();
+            let detail = (!details.is_empty()).then(|| details.join("; "));
+            if let Some(alert) = alerts.iter_mut().find(|a| a.kind == AlertKind::ScoreChange && a.event.id == id) {
+                alert.detail = detail.clone();
+            }
+            self.scoring.insert(id.clone(), plays.iter().map(play_key).collect());
+            summaries.push((id, summary, detail));
+        }
+        self.last = events.iter().map(|e| (e.id.clone(), e.clone())).collect();
+
+        for alert in alerts.iter().filter(|a| self.wants(&a.event)) {
+            info!(kind = ?alert.kind, event_id = %alert.event.id, "alert");
+            // A slow or failed delivery shouldn't hold up the scoreboard
+            if let Some(request) = self.webhook_request(alert) {
+                deliver(request, "webhook failed");
+            }
+            if let Some(request) = self.ntfy_request(alert) {
+                deliver(request, "ntfy publish failed");
+            }
+            if let Err(err) = self.run_hook(alert) {
+                warn!(%err, "on_event hook failed to start");
+            }
+        }
+        summaries
+    }
+
+    fn webhook_request(&self, alert: &Alert) -> Option<RequestBuilder> {
+        let webhook = self.webhook.as_ref().filter(|w| w.events.contains(&alert.kind))?;
+        let message = alert.message();
+        // `content` is what Discord shows and `text` is what Slack shows; each ignores the other
+        let payload = json!({
+            "content": message,
+            "text": message,
+            "kind": alert.kind,
+            "event_id": alert.event.id,
+            "name": alert.event.short_name,
+            "status": alert.event.status.type_field.short_detail,
+        });
+        Some(self.client.post(&webhook.url).json(&payload))
+    }
+
+    fn ntfy_request(&self, alert: &Alert) -> Option<RequestBuilder> {
+        let ntfy = self.ntfy.as_ref().filter(|n| n.events.contains(&alert.kind))?;
+        let url = format!("{}/{}", ntfy.server.trim_end_matches('/'), ntfy.topic);
+        // Finals are the ones worth buzzing a phone for
+        let priority = if alert.kind == AlertKind::Final { "high" } else { "default" };
+        Some(
+            self.client
+                .post(&url)
+                .header("Title", format!("{}: {}", alert.kind.label(), alert.event.short_name))
+                .header("Tags", "football")
+                .header("Priority", priority)
+                .body(match &alert.detail {
+                    Some(detail) => format!("{}\n{}", detail, score_line(&alert.event)),
+                    None => score_line(&alert.event),
+                }),
+        )
+    }
+
+    fn run_hook(&self, alert: &Alert) -> Result<()> {
+        let Some(hook) = self.hook.as_ref().filter(|h| h.events.contains(&alert.kind)) else {
+            return Ok(());
+        };
+        let mut child = shell(&hook.command)
+            .envs(hook_env(alert))
+            // Hooks must not draw over the TUI
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        // Reap it in the background so slow hooks don't hold up fetching
+        tokio::spawn(async move {
+            let _ = child.wait().await;
+        });
+        Ok(())
+    }
+}
+
+// How long a webhook or ntfy server gets to answer before the alert is given up on
+const DELIVERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+// Sends in the background, logging rather than returning failures
+fn deliver(request: RequestBuilder, failed: &'static str) {
+    tokio::spawn(async move {
+        if let Err(err) = request.timeout(DELIVERY_TIMEOUT).send().await.and_then(|resp| resp.error_for_status()) {
+            warn!(%err, "{}", failed);
+        }
+    });
+}
+
+/// `NFL_EVENT=score_change`, `NFL_AWAY=LAR`, `NFL_HOME_SCORE=7`, ...
+pub fn hook_env(alert: &Alert) -> Vec<(&'static str, String)> {
+    let comp = alert.event.competitions.first();
+    let side = |home_away: &str| comp.and_then(|c| c.competitors.iter().find(|c| c.home_away == home_away));
+    let kind = serde_json::to_value(alert.kind).ok().and_then(|v| v.as_str().map(String::from)).unwrap_or_default();
+    let mut env = vec![
+        ("NFL_EVENT", kind),
+        ("NFL_EVENT_ID", alert.event.id.clone()),
+        ("NFL_GAME", alert.event.short_name.clone()),
+        ("NFL_STATUS", alert.event.status.type_field.short_detail.clone()),
+        ("NFL_MESSAGE", alert.message()),
+    ];
+    for (team_var, score_var, home_away) in [("NFL_AWAY", "NFL_AWAY_SCORE", "away"), ("NFL_HOME", "NFL_HOME_SCORE", "home")] {
+        if let Some(c) = side(home_away) {
+            env.push((team_var, c.team.abbreviation.clone()));
+            env.push((score_var, c.score.clone().unwrap_or_default()));
+        }
+    }
+    if let Some(play) = comp.and_then(|c| c.situation.as_ref()).and_then(|s| s.last_play.as_ref()) {
+        env.push(("NFL_LAST_PLAY", play.text.clone()));
+    }
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{CuratedRank, PlayType, ScoreboardResponse, ScoringType};
+    use std::fs;
+
+    fn sample() -> Vec<Event> {
+        let content = fs::read_to_string("espn_data.json").expect("Failed to read sample file");
+        let data: ScoreboardResponse = serde_json::from_str(&content).expect("Failed to deserialize");
+        data.events
+    }
+
+    #[test]
+    fn test_detect() {
+        let next = sample();
+        let mut before = next[0].clone();
+        before.competitions[0].competitors[0].score = Some("0".to_string());
+        let prev = HashMap::from([(before.id.clone(), before)]);
+
+        let alerts = detect(&prev, &next);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::ScoreChange);
+
+        // Nothing changed, nothing to say
+        let same = next.iter().map(|e| (e.id.clone(), e.clone())).collect();
+        assert!(detect(&same, &next).is_empty());
+    }
+
+    #[test]
+    fn test_wants_follows_stars() {
+        let teams = Arc::new(Mutex::new(BTreeSet::from(["KC".to_string()])));
+        let notifier = Notifier::new(None, None, None, teams.clone(), CloseGameConfig::default(), None, Client::new());
+        let game = &sample()[0];
+        assert!(!notifier.wants(game));
+
+        // Starred after the notifier was made
+        teams.lock().unwrap().insert("LAR".to_string());
+        assert!(notifier.wants(game));
+        teams.lock().unwrap().remove("LAR");
+        assert!(!notifier.wants(game));
+    }
+
+    #[test]
+    fn test_detect_start_and_final() {
+        let next = sample();
+        let mut started = next[0].clone();
+        started.status.type_field.state = GameState::Pre;
+        let mut finished = next[1].clone();
+        finished.status.type_field.state = GameState::In;
+        let prev = HashMap::from([(started.id.clone(), started), (finished.id.clone(), finished)]);
+
+        let kinds: Vec<_> = detect(&prev, &next).into_iter().map(|a| a.kind).collect();
+        assert_eq!(kinds, vec![AlertKind::GameStart, AlertKind::Final]);
+    }
+
+    #[test]
+    fn test_detect_overtime() {
+        let mut next = sample();
+        next[0].status.period = 5;
+        let mut prev = next[0].clone();
+        prev.status.period = 4;
+        let prev = HashMap::from([(prev.id.clone(), prev)]);
+        let alerts = detect(&prev, &next[..1]);
+        assert_eq!(alerts.iter().map(|a| a.kind).collect::<Vec<_>>(), vec![AlertKind::Overtime]);
+        assert!(alerts[0].message().starts_with("OT!: LAR 0 @ ATL 7"));
+        assert_eq!(next[0].status.period_label(), "OT");
+        next[0].status.period = 6;
+        assert_eq!(next[0].status.period_label(), "2OT");
+    }
+
+    #[test]
+    fn test_detect_turnover() {
+        let mut next = sample();
+        let mut prev = next[0].clone();
+        prev.competitions[0].situation.as_mut().unwrap().last_play.as_mut().unwrap().id = Some("1".to_string());
+        let play = next[0].competitions[0].situation.as_mut().unwrap().last_play.as_mut().unwrap();
+        play.type_field = Some(PlayType { text: Some("Pass Interception Return".to_string()), abbreviation: None });
+
+        let prev = HashMap::from([(prev.id.clone(), prev)]);
+        let kinds: Vec<_> = detect(&prev, &next[..1]).into_iter().map(|a| a.kind).collect();
+        assert_eq!(kinds, vec![AlertKind::Turnover]);
+    }
+
+    #[test]
+    fn test_detect_close_games() {
+        let close = CloseGameConfig::default();
+        let mut late = sample()[0].clone();
+        late.status.period = 4;
+        late.status.clock = Some(240.0);
+        assert!(close.is_close(&late));
+
+        // Already close last time, so nothing new
+        let prev = HashMap::from([(late.id.clone(), late.clone())]);
+        assert!(detect_close_games(&prev, std::slice::from_ref(&late), &close).is_empty());
+
+        let mut earlier = late.clone();
+        earlier.status.clock = Some(400.0);
+        assert!(!close.is_close(&earlier));
+        let prev = HashMap::from([(earlier.id.clone(), earlier)]);
+        let alerts = detect_close_games(&prev, std::slice::from_ref(&late), &close);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::CloseGame);
+
+        // Two scores is not close
+        late.competitions[0].competitors[0].score = Some("17".to_string());
+        assert!(!close.is_close(&late));
+    }
+
+    #[test]
+    fn test_detect_upset_watch() {
+        let mut next = sample();
+        // LAR trail 7-0; make them ranked and move it to the third quarter
+        next[0].competitions[0].competitors[1].curated_rank = Some(CuratedRank { current: Some(5) });
+        next[0].status.period = 3;
+        assert!(next[0].upset_watch());
+
+        let mut prev = next[0].clone();
+        prev.status.period = 2;
+        assert!(!prev.upset_watch());
+        let prev = HashMap::from([(prev.id.clone(), prev)]);
+        let kinds: Vec<_> = detect(&prev, &next[..1]).into_iter().map(|a| a.kind).collect();
+        assert_eq!(kinds, vec![AlertKind::UpsetWatch]);
+
+        // #5 losing to #12 isn't much of an upset, but losing to #20 is
+        next[0].competitions[0].competitors[0].curated_rank = Some(CuratedRank { current: Some(12) });
+        assert!(!next[0].upset_watch());
+        next[0].competitions[0].competitors[0].curated_rank = Some(CuratedRank { current: Some(20) });
+        assert!(next[0].upset_watch());
+    }
+
+    #[test]
+    fn test_detect_kickoffs_soon() {
+        let mut game = sample()[0].clone();
+        game.status.type_field.state = GameState::Pre;
+        let kickoff = game.kickoff().expect("Missing kickoff");
+        let mut reminded = HashSet::new();
+        let lead = Duration::minutes(15);
+
+        let games = std::slice::from_ref(&game);
+        assert!(detect_kickoffs_soon(games, lead, kickoff - Duration::minutes(20), &mut reminded).is_empty());
+        let alerts = detect_kickoffs_soon(games, lead, kickoff - Duration::minutes(10), &mut reminded);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, AlertKind::KickoffSoon);
+        // Once is enough
+        assert!(detect_kickoffs_soon(games, lead, kickoff - Duration::minutes(5), &mut reminded).is_empty());
+    }
+
+    #[test]
+    fn test_new_scoring_plays() {
+        let content = fs::read_to_string("espn_summary.json").expect("Failed to read summary file");
+        let summary: Summary = serde_json::from_str(&content).expect("Failed to deserialize");
+        let plays = summary.scoring_plays();
+
+        // First look at the game: the touchdown and extra point took it from 0 to 7
+        let new = new_scoring_plays(None, &plays, 0);
+        assert_eq!(new.len(), 2);
+        assert_eq!(scoring_detail(new[0]), "ATL: M.Penix Jr. pass short middle to K.Pitts for 20 yards, TOUCHDOWN.");
+        assert_eq!(new_scoring_plays(None, &plays, 6).len(), 1);
+
+        let seen: HashSet<String> = plays[..1].iter().map(play_key).collect();
+        assert_eq!(new_scoring_plays(Some(&seen), &plays, 0), vec![&plays[1]]);
+
+        let mut typed = plays[0].clone();
+        typed.scoring_type = Some(ScoringType { display_name: Some("Touchdown".to_string()), abbreviation: Some("TD".to_string()) });
+        assert!(scoring_detail(&typed).starts_with("TD ATL: M.Penix Jr."));
+
+        let mut alert = Alert::new(AlertKind::ScoreChange, &sample()[0]);
+        alert.detail = Some(scoring_detail(&typed));
+        assert!(alert.message().starts_with("TD ATL: M.Penix Jr.") && alert.message().contains("LAR 0 @ ATL 7"));
+    }
+
+    #[test]
+    fn test_hook_env() {
+        let alert = Alert::new(AlertKind::ScoreChange, &sample()[0]);
+        let env: HashMap<_, _> = hook_env(&alert).into_iter().collect();
+        assert_eq!(env["NFL_EVENT"], "score_change");
+        assert_eq!(env["NFL_HOME"], "ATL");
+        assert_eq!(env["NFL_HOME_SCORE"], "7");
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use tokio::sync::mpsc::Sender;
+
+use crate::model::ScoreboardResponse;
+use crate::Update;
+
+/// Writes each scoreboard snapshot to `<dir>/<unix millis>.json`.
+pub struct Recorder {
+    dir: PathBuf,
+}
+
+impl Recorder {
+    pub fn new(dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        Ok(Self { dir })
+    }
+
+    pub fn save(&self, data: &ScoreboardResponse) -> Result<()> {
+        let millis = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let path = self.dir.join(format!("{}.json", millis));
+        fs::write(path, serde_json::to_vec(data)?)?;
+        Ok(())
+    }
+}
+
+/// Recorded snapshots in the order they were taken, as (unix millis, path).
+pub fn snapshots(dir: &Path) -> Result<Vec<(u64, PathBuf)>> {
+    let mut snapshots: Vec<(u64, PathBuf)> = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let millis = path.file_stem()?.to_str()?.parse().ok()?;
+            (path.extension()? == "json").then_some((millis, path))
+        })
+        .collect();
+    if snapshots.is_empty() {
+        bail!("No recorded snapshots in {}", dir.display());
+    }
+    snapshots.sort();
+    Ok(snapshots)
+}
+
+/// Feeds recorded snapshots to the UI with their original spacing divided by `speed`.
+pub async fn play(snapshots: Vec<(u64, PathBuf)>, speed: f64, tx: Sender<Update>) {
+    let mut previous = None;
+    for (millis, path) in snapshots {
+        if let Some(previous) = previous {
+            let gap = (millis - previous) as f64 / speed;
+            tokio::time::sleep(Duration::from_millis(gap as u64)).await;
+        }
+        previous = Some(millis);
+
+        // A snapshot that no longer parses is skipped rather than ending the replay
+        let Ok(content) = fs::read_to_string(&path) else { continue };
+        let Ok(data) = serde_json::from_str::<ScoreboardResponse>(&content) else { continue };
+        if tx.send(Update::Scoreboard(data)).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_then_list() {
+        let dir = std::env::temp_dir().join(format!("nfl-tui-replay-{}", std::process::id()));
+        let recorder = Recorder::new(dir.clone()).unwrap();
+        recorder.save(&ScoreboardResponse::default()).unwrap();
+        std::thread::sleep(Duration::from_millis(2));
+        recorder.save(&ScoreboardResponse::default()).unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let found = snapshots(&dir).unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found[0].0 < found[1].0);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::{Competitor, Event, GameState};
+
+pub fn draw_bowls(f: &mut Frame, bowls: Option<&[Event]>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" BOWLS ").borders(Borders::ALL);
+
+    let Some(bowls) = bowls else {
+        let p = Paragraph::new("Loading bowl games...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+    if bowls.is_empty() {
+        let p = Paragraph::new("No bowl games scheduled yet").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let mut games: Vec<&Event> = bowls.iter().collect();
+    games.sort_by_key(|e| e.kickoff());
+    let (playoff, others): (Vec<&Event>, Vec<&Event>) = games.into_iter().partition(|e| e.is_playoff());
+
+    let header = Row::new(["Date", "Game", "Matchup", "Result"]).style(Style::default().add_modifier(Modifier::BOLD));
+    let section = |name: &'static str| {
+        Row::new([Cell::from(""), Cell::from(name)]).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+    };
+
+    let mut rows = vec![];
+    if !playoff.is_empty() {
+        rows.push(section("COLLEGE FOOTBALL PLAYOFF"));
+        rows.extend(playoff.into_iter().map(game_row));
+        rows.push(Row::new([""]));
+    }
+    if !others.is_empty() {
+        rows.push(section("BOWLS"));
+        rows.extend(others.into_iter().map(game_row));
+    }
+
+    let widths = [
+        Constraint::Length(11),
+        Constraint::Min(24),
+        Constraint::Length(24),
+        Constraint::Length(18),
+    ];
+    let table = Table::new(rows, widths).header(header).block(block);
+    let mut state = TableState::default().with_offset(scroll as usize);
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn game_row(game: &Event) -> Row<'_> {
+    let date = game
+        .kickoff()
+        .map(|d| d.with_timezone(&Local).format("%a %b %-d").to_string())
+        .unwrap_or_default();
+    let side = |home_away: &str| game.competitions.first()?.competitors.iter().find(|c| c.home_away == home_away);
+    let matchup = match (side("away"), side("home")) {
+        (Some(a), Some(h)) => format!("{} vs {}", a.label(), h.label()),
+        _ => game.short_name.clone(),
+    };
+
+    let state = &game.status.type_field.state;
+    let (result, style) = match (state, side("away"), side("home")) {
+        (GameState::Pre, _, _) => (game.status.type_field.short_detail.clone(), Style::default().fg(Color::DarkGray)),
+        (_, Some(a), Some(h)) => {
+            let score = |c: &Competitor| format!("{} {}", c.team.abbreviation, c.score.as_deref().unwrap_or("0"));
+            let text = format!("{}, {}", score(a), score(h));
+            if *state == GameState::In {
+                (text, Style::default().fg(Color::Green))
+            } else {
+                (text, Style::default())
+            }
+        }
+        _ => (game.status.type_field.short_detail.clone(), Style::default()),
+    };
+
+    Row::new([
+        Cell::from(date),
+        Cell::from(game.note().unwrap_or_default().to_string()),
+        Cell::from(matchup),
+        Cell::from(result).style(style),
+    ])
+}
@@ -0,0 +1,220 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::{Boxscore, Summary};
+
+// Player categories worth the screen space, in display order
+const PLAYER_CATEGORIES: [&str; 3] = ["passing", "rushing", "receiving"];
+const MAX_STAT_COLUMNS: usize = 8;
+// Team totals compared with bars under the table, as (stat name, label)
+const COMPARED_STATS: [(&str, &str); 4] = [
+    ("totalYards", "Total Yards"),
+    ("netPassingYards", "Passing"),
+    ("rushingYards", "Rushing"),
+    ("firstDowns", "1st Downs"),
+];
+// Two bars plus the label row under them
+const COMPARISON_HEIGHT: u16 = 3;
+// Conversion rates worth calling out, as (stat name, label)
+const SITUATIONAL_STATS: [(&str, &str); 3] = [
+    ("thirdDownEff", "3rd Down"),
+    ("fourthDownEff", "4th Down"),
+    ("redZoneAttempts", "Red Zone"),
+];
+
+pub fn draw_boxscore(f: &mut Frame, summary: Option<&Summary>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" BOX SCORE ").borders(Borders::TOP);
+
+    let boxscore = match summary.and_then(|s| s.boxscore.as_ref()) {
+        Some(b) if !b.teams.is_empty() => b,
+        Some(_) => {
+            let p = Paragraph::new("No stats yet").alignment(Alignment::Center).block(block);
+            f.render_widget(p, area);
+            return;
+        }
+        None => {
+            let p = Paragraph::new("Loading box score...").alignment(Alignment::Center).block(block);
+            f.render_widget(p, area);
+            return;
+        }
+    };
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(38), Constraint::Min(0)].as_ref())
+        .split(inner);
+
+    // The comparison bars go under the table when there's room for them
+    let table_height = boxscore.teams[0].statistics.len() as u16 + 1;
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(table_height),
+            Constraint::Length(1),
+            // Header, the conversion rates, then turnovers and penalties
+            Constraint::Length(SITUATIONAL_STATS.len() as u16 + 3),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(chunks[0]);
+    f.render_widget(team_stats_table(boxscore), left[0]);
+    f.render_widget(situational_table(boxscore), left[2]);
+    if boxscore.teams.len() == 2 {
+        let bars = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(COMPARISON_HEIGHT); COMPARED_STATS.len()])
+            .split(left[4]);
+        for ((name, label), area) in COMPARED_STATS.into_iter().zip(bars.iter()) {
+            if area.height == COMPARISON_HEIGHT {
+                f.render_widget(comparison_bars(boxscore, name, label), *area);
+            }
+        }
+    }
+
+    let mut state = TableState::default().with_offset(scroll as usize);
+    f.render_stateful_widget(player_stats_table(boxscore), chunks[1], &mut state);
+}
+
+fn team_stats_table(boxscore: &Boxscore) -> Table<'_> {
+    let header_cells = std::iter::once(Cell::from("")).chain(boxscore.teams.iter().map(|t| {
+        Cell::from(t.team.abbreviation.clone().unwrap_or_default())
+    }));
+    let header = Row::new(header_cells).style(Style::default().add_modifier(Modifier::BOLD));
+
+    // Every team reports the same stat list, so the first one drives the rows
+    let rows = boxscore.teams[0].statistics.iter().map(|stat| {
+        let label = stat.label.clone().unwrap_or_else(|| stat.name.clone());
+        let values = boxscore.teams.iter().map(|t| {
+            let value = t
+                .statistics
+                .iter()
+                .find(|s| s.name == stat.name)
+                .map(|s| s.display_value.clone())
+                .unwrap_or_default();
+            Cell::from(value)
+        });
+        Row::new(std::iter::once(Cell::from(label)).chain(values))
+    });
+
+    Table::new(rows, [Constraint::Length(20), Constraint::Length(7), Constraint::Length(7)])
+        .header(header)
+        .block(Block::default().borders(Borders::RIGHT))
+}
+
+// "4/11 36%" for each team's third down, fourth down and red zone tries, then giveaways and flags
+fn situational_table(boxscore: &Boxscore) -> Table<'_> {
+    let header_cells = std::iter::once(Cell::from("Situational")).chain(boxscore.teams.iter().map(|t| {
+        Cell::from(t.team.abbreviation.clone().unwrap_or_default())
+    }));
+    let header = Row::new(header_cells).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+
+    let rows = SITUATIONAL_STATS.iter().map(|(name, label)| {
+        let values = boxscore.teams.iter().map(|t| {
+            let text = match t.conversions(name) {
+                Some((made, attempts)) if attempts > 0 => format!("{}/{} {}%", made, attempts, made * 100 / attempts),
+                Some((made, attempts)) => format!("{}/{}", made, attempts),
+                None => "-".to_string(),
+            };
+            Cell::from(text)
+        });
+        Row::new(std::iter::once(Cell::from(*label)).chain(values))
+    });
+    let turnovers = boxscore.teams.iter().map(|t| {
+        let count = |name: &str| t.stat(name).unwrap_or("0").to_string();
+        let style = if count("turnovers") == "0" { Style::default() } else { Style::default().fg(Color::Red) };
+        Cell::from(format!("{} INT, {} FL", count("interceptions"), count("fumblesLost"))).style(style)
+    });
+    // "totalPenaltiesYards" is "count-yards"
+    let penalties = boxscore.teams.iter().map(|t| {
+        let text = match t.conversions("totalPenaltiesYards") {
+            Some((count, yards)) => format!("{} for {}", count, yards),
+            None => "-".to_string(),
+        };
+        Cell::from(text)
+    });
+    let rows = rows
+        .chain(std::iter::once(Row::new(std::iter::once(Cell::from("Turnovers")).chain(turnovers))))
+        .chain(std::iter::once(Row::new(std::iter::once(Cell::from("Penalties")).chain(penalties))));
+
+    Table::new(rows, [Constraint::Length(12), Constraint::Length(11), Constraint::Length(11)])
+        .header(header)
+        .block(Block::default().borders(Borders::RIGHT))
+}
+
+// One stat for both teams, scaled to the larger of the two
+fn comparison_bars<'a>(boxscore: &'a Boxscore, name: &str, label: &'a str) -> BarChart<'a> {
+    let colors = [Color::Cyan, Color::Yellow];
+    let bars: Vec<Bar> = boxscore
+        .teams
+        .iter()
+        .zip(colors)
+        .map(|(team, color)| {
+            let value = team.stat(name).unwrap_or("0");
+            Bar::default()
+                .label(Line::from(team.team.abbreviation.clone().unwrap_or_default()))
+                // Rushing yards can go negative; the bar can't
+                .value(value.parse::<i64>().unwrap_or(0).max(0) as u64)
+                .text_value(value.to_string())
+                .style(Style::default().fg(color))
+                .value_style(Style::default().fg(Color::Black).bg(color))
+        })
+        .collect();
+
+    BarChart::default()
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().label(Line::from(label).centered()).bars(&bars))
+        .bar_width(1)
+        .bar_gap(0)
+        .group_gap(1)
+        .label_style(Style::default().fg(Color::DarkGray))
+        .block(Block::default().borders(Borders::RIGHT))
+}
+
+fn player_stats_table(boxscore: &Boxscore) -> Table<'_> {
+    let mut rows = vec![];
+    for category in PLAYER_CATEGORIES {
+        let mut header_written = false;
+        for team in &boxscore.players {
+            let Some(stats) = team.statistics.iter().find(|c| c.name == category) else {
+                continue;
+            };
+            if !header_written {
+                let title = stats.text.clone().unwrap_or_else(|| category.to_string());
+                let cells = [Cell::from(""), Cell::from(title)]
+                    .into_iter()
+                    .chain(stats.labels.iter().take(MAX_STAT_COLUMNS).map(|l| Cell::from(l.clone())));
+                rows.push(Row::new(cells).style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+                header_written = true;
+            }
+
+            let abbr = team.team.abbreviation.clone().unwrap_or_default();
+            for athlete in &stats.athletes {
+                let name = athlete
+                    .athlete
+                    .short_name
+                    .clone()
+                    .unwrap_or_else(|| athlete.athlete.display_name.clone());
+                let cells = [Cell::from(abbr.clone()), Cell::from(name)]
+                    .into_iter()
+                    .chain(athlete.stats.iter().take(MAX_STAT_COLUMNS).map(|v| Cell::from(v.clone())));
+                rows.push(Row::new(cells));
+            }
+        }
+        if header_written {
+            rows.push(Row::new([Cell::from("")]));
+        }
+    }
+
+    let widths = [Constraint::Length(4), Constraint::Length(16)]
+        .into_iter()
+        .chain(std::iter::repeat_n(Constraint::Length(6), MAX_STAT_COLUMNS));
+    Table::new(rows, widths).column_spacing(1)
+}
@@ -0,0 +1,95 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::data::Bracket;
+use crate::model::{Competitor, Event, GameState};
+
+pub fn draw_bracket(f: &mut Frame, bracket: Option<&Bracket>, area: Rect) {
+    let block = Block::default().title(" PLAYOFF BRACKET ").borders(Borders::ALL);
+
+    let Some(bracket) = bracket else {
+        let p = Paragraph::new("Loading playoff bracket...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, bracket.rounds.len().max(1) as u32); bracket.rounds.len()])
+        .split(inner);
+    for ((name, games), column) in bracket.rounds.iter().zip(columns.iter()) {
+        let lines = round_lines(bracket, games);
+        let p = Paragraph::new(lines).block(
+            Block::default()
+                .title(format!(" {} ", name.to_uppercase()))
+                .title_alignment(Alignment::Center)
+                .borders(Borders::BOTTOM),
+        );
+        f.render_widget(p, *column);
+    }
+}
+
+// AFC games above NFC ones, each conference in seed order
+fn round_lines<'a>(bracket: &'a Bracket, games: &'a [Event]) -> Vec<Line<'a>> {
+    if games.is_empty() {
+        return vec![Line::from(Span::styled("TBD", Style::default().fg(Color::DarkGray)))];
+    }
+
+    let conference_and_seed = |game: &Event| -> (String, u32) {
+        let competitors = game.competitions.first().map(|c| c.competitors.as_slice()).unwrap_or_default();
+        competitors
+            .iter()
+            .filter_map(|c| bracket.seeds.get(&c.team.abbreviation))
+            .min_by_key(|(_, seed)| *seed)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let mut games: Vec<&Event> = games.iter().collect();
+    games.sort_by_key(|g| conference_and_seed(g));
+
+    let mut lines = vec![];
+    let mut conference = None;
+    for game in games {
+        let (game_conference, _) = conference_and_seed(game);
+        if !game_conference.is_empty() && conference.as_ref() != Some(&game_conference) {
+            lines.push(Line::from(Span::styled(
+                game_conference.clone(),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            )));
+            conference = Some(game_conference);
+        }
+
+        let Some(comp) = game.competitions.first() else { continue };
+        for home_away in ["away", "home"] {
+            if let Some(c) = comp.competitors.iter().find(|c| c.home_away == home_away) {
+                lines.push(team_line(bracket, game, c));
+            }
+        }
+        lines.push(Line::from(Span::styled(
+            format!("  {}", game.status.type_field.short_detail),
+            Style::default().fg(Color::DarkGray),
+        )));
+        lines.push(Line::from(""));
+    }
+    lines
+}
+
+// "(1) KC   31", with the winner in bold and the loser dimmed
+fn team_line<'a>(bracket: &Bracket, game: &Event, c: &'a Competitor) -> Line<'a> {
+    let seed = bracket.seeds.get(&c.team.abbreviation).map(|(_, s)| format!("({})", s)).unwrap_or_default();
+    let score = if game.status.type_field.state == GameState::Pre { "" } else { c.score.as_deref().unwrap_or("") };
+    let style = match c.winner {
+        Some(true) => Style::default().add_modifier(Modifier::BOLD),
+        Some(false) if game.status.type_field.state == GameState::Post => Style::default().fg(Color::DarkGray),
+        _ => Style::default(),
+    };
+    Line::from(Span::styled(format!("{:>4} {:<4} {:>3}", seed, c.team.abbreviation, score), style))
+}
@@ -0,0 +1,85 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use crate::model::{Competitor, Drive};
+
+// Most recent drives shown; older ones are in the play-by-play view
+pub const MAX_ROWS: usize = 6;
+const LABEL_WIDTH: u16 = 24;
+
+/// One row per drive: a bar from the drive's start to end, oriented so every
+/// offense drives left to right, colored by the team that had the ball.
+pub struct DriveChart<'a> {
+    pub drives: &'a [&'a Drive],
+    pub home: &'a Competitor,
+    pub away: &'a Competitor,
+}
+
+impl DriveChart<'_> {
+    fn team_color(&self, abbr: &str) -> Color {
+        let team = if abbr == self.home.team.abbreviation { &self.home.team } else { &self.away.team };
+        crate::parse_color(team.color.as_deref().unwrap_or("888888"))
+    }
+}
+
+impl Widget for DriveChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width <= LABEL_WIDTH + 10 || area.height == 0 {
+            return;
+        }
+        let bar_width = (area.width - LABEL_WIDTH) as f64;
+        let rows = area.height as usize;
+        let start = self.drives.len().saturating_sub(rows);
+
+        for (row, drive) in self.drives[start..].iter().enumerate() {
+            let y = area.top() + row as u16;
+            let abbr = drive.team.as_ref().and_then(|t| t.abbreviation.clone()).unwrap_or_default();
+            let color = self.team_color(&abbr);
+
+            let result = match drive.display_result.as_deref() {
+                Some(r) if !r.is_empty() => r,
+                _ => "...",
+            };
+            let label = format!(
+                "{:<4}{:<11}{:>2}p {:>3}y",
+                abbr,
+                result.chars().take(10).collect::<String>(),
+                drive.offensive_plays.unwrap_or(0),
+                drive.yards.unwrap_or(0)
+            );
+            let label_style = if drive.is_score {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            buf.set_stringn(area.left(), y, &label, LABEL_WIDTH as usize, label_style);
+
+            // Field track, then the bar over it
+            let track_left = area.left() + LABEL_WIDTH;
+            for x in track_left..area.right() {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char('·').set_fg(Color::DarkGray);
+                }
+            }
+
+            let Some(to_go) = drive.start.as_ref().and_then(|s| s.yard_line) else {
+                continue;
+            };
+            let from = (100 - to_go).clamp(0, 100) as f64;
+            let to = (from + drive.yards.unwrap_or(0) as f64).clamp(0.0, 100.0);
+            let (lo, hi) = if from <= to { (from, to) } else { (to, from) };
+            let lo_col = track_left + (lo / 100.0 * bar_width) as u16;
+            // Always draw at least one cell so zero-yard drives remain visible
+            let hi_col = (track_left + (hi / 100.0 * bar_width) as u16).max(lo_col + 1);
+            for x in lo_col..hi_col.min(area.right()) {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(' ').set_bg(color);
+                }
+            }
+        }
+    }
+}
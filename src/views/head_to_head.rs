@@ -0,0 +1,53 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::data::HEAD_TO_HEAD_GAMES;
+use crate::model::{SeriesCompetitor, SeriesEvent};
+
+pub fn draw_head_to_head(f: &mut Frame, meetings: Option<&[SeriesEvent]>, area: Rect) {
+    let block = Block::default().title(format!(" LAST {} MEETINGS ", HEAD_TO_HEAD_GAMES)).borders(Borders::TOP);
+
+    let Some(meetings) = meetings else {
+        let p = Paragraph::new("Loading head-to-head...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+    if meetings.is_empty() {
+        let p = Paragraph::new("No recent meetings").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    let header = Row::new(["Date", "Result", "Winner"]).style(Style::default().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = meetings.iter().map(meeting_row).collect();
+    let widths = [Constraint::Length(12), Constraint::Min(20), Constraint::Length(8)];
+    let table = Table::new(rows, widths).header(header).block(block);
+    f.render_widget(table, area);
+}
+
+fn meeting_row(meeting: &SeriesEvent) -> Row<'_> {
+    let side = |home_away: &str| meeting.competitors.iter().find(|c| c.home_away.as_deref() == Some(home_away));
+    let abbreviation = |c: &SeriesCompetitor| c.team.abbreviation.clone().or_else(|| c.team.display_name.clone()).unwrap_or_default();
+    let result = match (side("away"), side("home")) {
+        (Some(a), Some(h)) => format!(
+            "{} {} @ {} {}",
+            abbreviation(a),
+            a.score.as_deref().unwrap_or("-"),
+            abbreviation(h),
+            h.score.as_deref().unwrap_or("-")
+        ),
+        _ => String::new(),
+    };
+    let winner = meeting.competitors.iter().find(|c| c.winner == Some(true)).map(abbreviation).unwrap_or_else(|| "Tie".to_string());
+    let date = meeting.date.as_deref().and_then(|d| d.get(..10)).unwrap_or_default().to_string();
+
+    Row::new([
+        Cell::from(date).style(Style::default().fg(Color::DarkGray)),
+        Cell::from(result),
+        Cell::from(winner).style(Style::default().fg(Color::Yellow)),
+    ])
+}
@@ -0,0 +1,55 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::keymap::KeyMap;
+
+/// Modal listing every key binding plus the current runtime settings.
+pub struct HelpOverlay<'a> {
+    pub keymap: &'a KeyMap,
+    pub settings: Vec<(&'static str, String)>,
+}
+
+impl Widget for HelpOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let key_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+        let heading_style = Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD);
+
+        let mut lines = vec![Line::from(Span::styled("Keys", heading_style))];
+        for (action, keys) in self.keymap.labels() {
+            if keys.is_empty() {
+                continue;
+            }
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16}", keys.join(", ")), key_style),
+                Span::raw(action.description()),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Settings", heading_style)));
+        for (name, value) in &self.settings {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<16}", name), Style::default().fg(Color::Gray)),
+                Span::raw(value.clone()),
+            ]));
+        }
+
+        let height = lines.len() as u16 + 2;
+        let popup = super::centered_rect(56, height, area);
+        Clear.render(popup, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(" HELP ")
+                    .title_bottom(Line::from(" Esc to close ").alignment(Alignment::Right))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .render(popup, buf);
+    }
+}
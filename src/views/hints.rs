@@ -0,0 +1,36 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::keymap::{Action, KeyMap};
+
+/// The bottom line: keys that make sense right now on the left, and how fresh the
+/// scores are on the right.
+pub struct HintBar<'a> {
+    pub keymap: &'a KeyMap,
+    pub hints: &'a [(Action, &'static str)],
+    pub updated: Option<String>,
+}
+
+impl Widget for HintBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![];
+        for (action, label) in self.hints {
+            // Unbound actions have nothing to show
+            let Some(key) = self.keymap.key_for(*action) else { continue };
+            spans.push(Span::styled(format!(" {} ", key), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+            spans.push(Span::styled(format!("{}  ", label), Style::default().fg(Color::Gray)));
+        }
+        Paragraph::new(Line::from(spans)).render(area, buf);
+
+        if let Some(updated) = self.updated {
+            Paragraph::new(Span::styled(format!("{} ", updated), Style::default().fg(Color::DarkGray)))
+                .alignment(Alignment::Right)
+                .render(area, buf);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use ratatui::layout::Rect;
+
+pub mod bowls;
+pub mod boxscore;
+pub mod bracket;
+pub mod drive_chart;
+pub mod head_to_head;
+pub mod help;
+pub mod hints;
+pub mod news;
+pub mod picker;
+pub mod plays;
+pub mod preview;
+pub mod rankings;
+pub mod scoring;
+pub mod standings;
+pub mod ticker;
+pub mod toasts;
+
+/// A `width` x `height` rect centered in `area`, clamped to fit.
+pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::model::News;
+
+pub fn draw_news(f: &mut Frame, news: Option<&News>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" HEADLINES ").borders(Borders::ALL);
+
+    let Some(news) = news else {
+        let p = Paragraph::new("Loading headlines...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let now = Utc::now();
+    let mut lines = vec![];
+    for article in &news.articles {
+        let mut headline = vec![Span::styled(article.headline.clone(), Style::default().add_modifier(Modifier::BOLD))];
+        if let Some(age) = article.published.as_deref().and_then(|p| age(p, now)) {
+            headline.push(Span::styled(format!("  {}", age), Style::default().fg(Color::DarkGray)));
+        }
+        lines.push(Line::from(headline));
+
+        if let Some(description) = &article.description {
+            lines.push(Line::from(Span::styled(description.clone(), Style::default().fg(Color::Gray))));
+        }
+        lines.push(Line::from(""));
+    }
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0));
+    f.render_widget(p, area);
+}
+
+// "5m ago" / "3h ago" / "2d ago" from an RFC 3339 timestamp
+fn age(published: &str, now: DateTime<Utc>) -> Option<String> {
+    let published = DateTime::parse_from_rfc3339(published).ok()?;
+    let minutes = (now - published.with_timezone(&Utc)).num_minutes().max(0);
+    Some(if minutes < 60 {
+        format!("{}m ago", minutes)
+    } else if minutes < 60 * 24 {
+        format!("{}h ago", minutes / 60)
+    } else {
+        format!("{}d ago", minutes / (60 * 24))
+    })
+}
@@ -0,0 +1,26 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+/// A modal list to choose one item from; the caller handles the keys.
+pub fn draw_picker(f: &mut Frame, title: &str, items: &[&str], state: &mut ListState, area: Rect) {
+    let width = items.iter().map(|i| i.len()).max().unwrap_or(0).max(title.len()).max(30) as u16 + 4;
+    let popup = super::centered_rect(width, items.len() as u16 + 2, area);
+    f.render_widget(Clear, popup);
+
+    let list = List::new(items.iter().map(|i| ListItem::new(*i)))
+        .block(
+            Block::default()
+                .title(format!(" {} ", title))
+                .title_bottom(Line::from(" Enter to pick, Esc to cancel ").alignment(Alignment::Right))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD).bg(Color::DarkGray).fg(Color::White))
+        .highlight_symbol("> ");
+    f.render_stateful_widget(list, popup, state);
+}
@@ -0,0 +1,79 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::model::{Play, Summary};
+
+pub fn draw_plays(f: &mut Frame, summary: Option<&Summary>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" PLAY-BY-PLAY ").borders(Borders::TOP);
+
+    let Some(summary) = summary else {
+        let p = Paragraph::new("Loading plays...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let drives = summary.drives();
+    if drives.is_empty() {
+        let p = Paragraph::new("No plays yet").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    // Newest drive first so live games don't need scrolling
+    let mut lines = vec![];
+    for drive in drives.iter().rev() {
+        let team = drive
+            .team
+            .as_ref()
+            .and_then(|t| t.abbreviation.clone())
+            .unwrap_or_default();
+        let result = match drive.display_result.as_deref() {
+            Some(r) if !r.is_empty() => r,
+            _ => "In Progress",
+        };
+        let description = drive.description.clone().unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(format!(" {} ", team), Style::default().bg(Color::White).fg(Color::Black).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("  {}", result), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("  {}", description), Style::default().fg(Color::Gray)),
+        ]));
+
+        for play in drive.plays.iter().rev() {
+            lines.push(play_line(play));
+        }
+        lines.push(Line::from(""));
+    }
+
+    let p = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0));
+    f.render_widget(p, area);
+}
+
+fn play_line(play: &Play) -> Line<'_> {
+    let period = play.period.as_ref().map(|p| p.number).unwrap_or(0);
+    let clock = play.clock.as_ref().map(|c| c.display_value.as_str()).unwrap_or("");
+    let situation = play
+        .start
+        .as_ref()
+        .and_then(|s| s.short_down_distance_text.clone())
+        .unwrap_or_default();
+
+    let text_style = if play.scoring_play {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    Line::from(vec![
+        Span::styled(format!("  Q{} {:>5}  ", period, clock), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!("{:<9}", situation), Style::default().fg(Color::Cyan)),
+        Span::styled(play.text.clone(), text_style),
+    ])
+}
@@ -0,0 +1,132 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::model::{Competition, Competitor, Event, Summary, TeamInjuries};
+
+// Injuries worth listing; "Probable" and day-to-day players aren't
+const KEY_STATUSES: &[&str] = &["Out", "Doubtful", "Questionable", "Injured Reserve"];
+const INJURIES_PER_TEAM: usize = 4;
+
+/// Takes the place of the (empty) field before kickoff.
+pub fn draw_preview(f: &mut Frame, game: &Event, comp: &Competition, summary: Option<&Summary>, away: &Competitor, home: &Competitor, area: Rect) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+
+    let matchup = Paragraph::new(matchup_lines(game, comp, summary, away, home))
+        .block(Block::default().title(" MATCHUP ").borders(Borders::ALL))
+        .wrap(Wrap { trim: true });
+    f.render_widget(matchup, columns[0]);
+
+    let injuries = summary.map(|s| s.injuries.as_slice()).unwrap_or_default();
+    let mut lines = vec![];
+    for c in [away, home] {
+        lines.extend(injury_lines(injuries, c));
+    }
+    if lines.is_empty() {
+        let text = if summary.is_some() { "No key injuries reported" } else { "Loading..." };
+        lines.push(Line::from(Span::styled(text, Style::default().fg(Color::DarkGray))));
+    }
+    let injuries = Paragraph::new(lines)
+        .block(Block::default().title(" KEY INJURIES ").borders(Borders::ALL))
+        .wrap(Wrap { trim: true });
+    f.render_widget(injuries, columns[1]);
+}
+
+fn matchup_lines<'a>(game: &Event, comp: &Competition, summary: Option<&'a Summary>, away: &Competitor, home: &Competitor) -> Vec<Line<'a>> {
+    let label = |text: &'static str| Span::styled(format!("{:<10}", text), Style::default().fg(Color::DarkGray));
+    let mut lines = vec![];
+
+    if let Some(kickoff) = game.kickoff() {
+        let when = kickoff.with_timezone(&Local).format("%a %b %-d, %-I:%M %p").to_string();
+        lines.push(Line::from(vec![label("Kickoff"), Span::raw(when)]));
+    }
+    if let Some(venue) = summary.and_then(|s| s.game_info.as_ref()).and_then(|g| g.venue.as_ref()) {
+        let city = venue.address.as_ref().and_then(|a| a.city.as_deref());
+        let text = match city {
+            Some(city) => format!("{}, {}", venue.full_name, city),
+            None => venue.full_name.clone(),
+        };
+        lines.push(Line::from(vec![label("Venue"), Span::raw(text)]));
+    }
+    if let Some(weather) = game.weather.as_ref().and_then(|w| w.summary()) {
+        lines.push(Line::from(vec![label("Weather"), Span::raw(weather)]));
+    }
+    let networks: Vec<String> = comp.broadcasts.iter().flatten().flat_map(|b| b.names.clone()).collect();
+    if !networks.is_empty() {
+        lines.push(Line::from(vec![label("TV"), Span::styled(networks.join(", "), Style::default().fg(Color::Cyan))]));
+    }
+    if let Some(odds) = comp.odds.as_ref().and_then(|o| o.first()) {
+        let mut text = odds.details.clone().unwrap_or_default();
+        if let Some(total) = odds.over_under {
+            text.push_str(&format!("  O/U {}", total));
+        }
+        lines.push(Line::from(vec![label("Line"), Span::raw(text)]));
+    }
+
+    lines.push(Line::from(""));
+    for (c, is_home) in [(away, false), (home, true)] {
+        let mut text = c.record().unwrap_or("-").to_string();
+        if let Some(split_record) = c.venue_record(is_home) {
+            text.push_str(&format!("  ({} {})", split_record, if is_home { "home" } else { "away" }));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<10}", c.label()), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(text),
+        ]));
+    }
+
+    // Earlier meetings this season
+    for series in summary.map(|s| s.season_series.as_slice()).unwrap_or_default() {
+        let Some(text) = series.summary.as_deref().filter(|s| !s.is_empty()) else { continue };
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![label("Series"), Span::raw(text.to_string())]));
+        for meeting in &series.events {
+            let score = meeting
+                .competitors
+                .iter()
+                .map(|c| format!("{} {}", c.team.abbreviation.as_deref().unwrap_or("?"), c.score.as_deref().unwrap_or("-")))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let date = meeting.date.as_deref().and_then(|d| d.get(..10)).unwrap_or_default();
+            lines.push(Line::from(vec![Span::raw(format!("{:<10}", "")), Span::styled(format!("{}  {}", date, score), Style::default().fg(Color::Gray))]));
+        }
+    }
+    lines
+}
+
+fn injury_lines<'a>(injuries: &'a [TeamInjuries], c: &Competitor) -> Vec<Line<'a>> {
+    let Some(team) = injuries.iter().find(|t| t.team.abbreviation.as_deref() == Some(c.team.abbreviation.as_str()) || (t.team.id.is_some() && t.team.id == c.team.id)) else {
+        return vec![];
+    };
+    let key: Vec<_> = team
+        .injuries
+        .iter()
+        .filter(|i| i.status.as_deref().is_some_and(|s| KEY_STATUSES.contains(&s)))
+        .take(INJURIES_PER_TEAM)
+        .collect();
+    if key.is_empty() {
+        return vec![];
+    }
+
+    let mut lines = vec![Line::from(Span::styled(c.team.abbreviation.clone(), Style::default().add_modifier(Modifier::BOLD)))];
+    for injury in key {
+        let Some(athlete) = &injury.athlete else { continue };
+        let position = athlete.position.as_ref().and_then(|p| p.abbreviation.as_deref()).unwrap_or("");
+        let status = injury.status.as_deref().unwrap_or_default();
+        let color = if status == "Questionable" { Color::Yellow } else { Color::Red };
+        lines.push(Line::from(vec![
+            Span::raw(format!("  {:<3} {} ", position, athlete.display_name)),
+            Span::styled(status, Style::default().fg(color)),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines
+}
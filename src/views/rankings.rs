@@ -0,0 +1,69 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::{Poll, Rank, Rankings};
+
+pub fn draw_rankings(f: &mut Frame, rankings: Option<&Rankings>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" TOP 25 ").borders(Borders::ALL);
+
+    let polls = rankings.map(|r| r.main_polls()).unwrap_or_default();
+    if polls.is_empty() {
+        let text = if rankings.is_some() { "No polls released yet" } else { "Loading rankings..." };
+        let p = Paragraph::new(text).alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    }
+
+    // Polls side by side
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, polls.len() as u32); polls.len()])
+        .split(area);
+    for (poll, column) in polls.iter().zip(columns.iter()) {
+        draw_poll(f, poll, scroll, *column);
+    }
+}
+
+fn draw_poll(f: &mut Frame, poll: &Poll, scroll: u16, area: Rect) {
+    let title = format!(" {} ", poll.short_name.as_deref().unwrap_or(&poll.name).to_uppercase());
+    let header = Row::new(["#", "Team", "Rec", "Pts", "+/-"]).style(Style::default().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = poll.ranks.iter().map(rank_row).collect();
+
+    let widths = [
+        Constraint::Length(3),
+        Constraint::Min(12),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(4),
+    ];
+    let table = Table::new(rows, widths).header(header).block(Block::default().title(title).borders(Borders::ALL));
+    let mut state = TableState::default().with_offset(scroll as usize);
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn rank_row(rank: &Rank) -> Row<'_> {
+    let mut team = rank.team.location.clone().or_else(|| rank.team.abbreviation.clone()).unwrap_or_default();
+    if let Some(votes) = rank.first_place_votes.filter(|v| *v > 0) {
+        team.push_str(&format!(" ({})", votes));
+    }
+
+    // Movement since last week; 0 or missing means the team is newly ranked
+    let (movement, movement_style) = match rank.previous.filter(|p| *p > 0) {
+        Some(previous) if previous > rank.current => (format!("▲{}", previous - rank.current), Style::default().fg(Color::Green)),
+        Some(previous) if previous < rank.current => (format!("▼{}", rank.current - previous), Style::default().fg(Color::Red)),
+        Some(_) => ("-".to_string(), Style::default().fg(Color::DarkGray)),
+        None => ("NEW".to_string(), Style::default().fg(Color::Yellow)),
+    };
+
+    Row::new([
+        Cell::from(rank.current.to_string()),
+        Cell::from(team),
+        Cell::from(rank.record_summary.clone().unwrap_or_default()),
+        Cell::from(rank.points.map(|p| format!("{:.0}", p)).unwrap_or_default()),
+        Cell::from(movement).style(movement_style),
+    ])
+}
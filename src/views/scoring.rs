@@ -0,0 +1,56 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::{Competitor, ScoringPlay};
+
+/// Every score so far with the running score after it, oldest first. When it doesn't
+/// all fit, the most recent scores stay in view.
+pub fn draw_scoring_summary(f: &mut Frame, plays: &[ScoringPlay], away: &Competitor, home: &Competitor, area: Rect) {
+    let block = Block::default().title(" SCORING SUMMARY ").borders(Borders::TOP);
+
+    let rows: Vec<Row> = plays.iter().map(|play| scoring_row(play, away, home)).collect();
+    let widths = [
+        Constraint::Length(3),
+        Constraint::Length(5),
+        Constraint::Length(4),
+        Constraint::Min(20),
+        Constraint::Length(16),
+    ];
+    let table = Table::new(rows, widths).block(block).column_spacing(1);
+
+    let visible = area.height.saturating_sub(1) as usize;
+    let mut state = TableState::default().with_offset(plays.len().saturating_sub(visible));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn scoring_row<'a>(play: &'a ScoringPlay, away: &Competitor, home: &Competitor) -> Row<'a> {
+    let quarter = match play.period.as_ref().map(|p| p.number) {
+        Some(n @ 1..=4) => format!("Q{}", n),
+        Some(5) => "OT".to_string(),
+        Some(n) => format!("{}OT", n - 4),
+        None => String::new(),
+    };
+    let clock = play.clock.as_ref().map(|c| c.display_value.clone()).unwrap_or_default();
+    let team = play.team.as_ref().and_then(|t| t.abbreviation.clone()).unwrap_or_default();
+    let score = match (play.away_score, play.home_score) {
+        (Some(a), Some(h)) => format!("{} {} - {} {}", away.team.abbreviation, a, h, home.team.abbreviation),
+        _ => String::new(),
+    };
+    let kind = play.scoring_type.as_ref().and_then(|t| t.abbreviation.clone());
+    let text = match kind {
+        Some(kind) => format!("{}: {}", kind, play.text),
+        None => play.text.clone(),
+    };
+
+    Row::new([
+        Cell::from(quarter).style(Style::default().fg(Color::DarkGray)),
+        Cell::from(clock).style(Style::default().fg(Color::DarkGray)),
+        Cell::from(team).style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from(text),
+        Cell::from(score).style(Style::default().fg(Color::Yellow)),
+    ])
+}
@@ -0,0 +1,84 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::{Standings, StandingsEntry};
+
+pub fn draw_standings(f: &mut Frame, standings: Option<&Standings>, scroll: u16, area: Rect) {
+    let block = Block::default().title(" STANDINGS ").borders(Borders::ALL);
+
+    let Some(standings) = standings else {
+        let p = Paragraph::new("Loading standings...").alignment(Alignment::Center).block(block);
+        f.render_widget(p, area);
+        return;
+    };
+
+    let header = Row::new(["", "Team", "W", "L", "T", "PCT", "HOME", "AWAY", "DIV", "STRK", "SEED"])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+    let mut rows = vec![];
+    for group in standings.groups() {
+        rows.push(Row::new([Cell::from(""), Cell::from(group.name.clone())])
+            .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)));
+
+        let entries = group.standings.as_ref().map(|s| s.entries.as_slice()).unwrap_or_default();
+        for entry in entries {
+            rows.push(entry_row(entry));
+        }
+        rows.push(Row::new([""]));
+    }
+
+    let widths = [
+        Constraint::Length(4),
+        Constraint::Min(22),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(3),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(5),
+        Constraint::Length(5),
+        Constraint::Length(4),
+    ];
+    let table = Table::new(rows, widths).header(header).block(block);
+    let mut state = TableState::default().with_offset(scroll as usize);
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn entry_row(entry: &StandingsEntry) -> Row<'_> {
+    let stat = |key: &str| entry.stat(key).unwrap_or("-").to_string();
+
+    let streak = stat("streak");
+    let streak_style = if streak.starts_with('W') {
+        Style::default().fg(Color::Green)
+    } else if streak.starts_with('L') {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+
+    // Seeds 1-7 make the playoffs
+    let seed = stat("playoffSeed");
+    let seed_style = match seed.parse::<u32>() {
+        Ok(n) if n <= 7 => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        _ => Style::default().fg(Color::DarkGray),
+    };
+
+    Row::new([
+        Cell::from(entry.team.abbreviation.clone().unwrap_or_default()),
+        Cell::from(entry.team.display_name.clone().unwrap_or_default()),
+        Cell::from(stat("wins")),
+        Cell::from(stat("losses")),
+        Cell::from(stat("ties")),
+        Cell::from(stat("winPercent")),
+        Cell::from(stat("home")),
+        Cell::from(stat("road")),
+        Cell::from(stat("vsdiv")),
+        Cell::from(streak).style(streak_style),
+        Cell::from(seed).style(seed_style),
+    ])
+}
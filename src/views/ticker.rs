@@ -0,0 +1,71 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
+};
+
+use crate::model::{Event, GameState};
+
+// Columns scrolled per second
+const SPEED: u128 = 8;
+const SEPARATOR: &str = "   •   ";
+
+/// A single line of scores for every game but the one on screen, scrolling
+/// right to left and wrapping around forever.
+pub struct Ticker<'a> {
+    pub events: Vec<&'a Event>,
+    pub elapsed_ms: u128,
+}
+
+fn game_spans(event: &Event) -> Vec<Span<'static>> {
+    let Some(comp) = event.competitions.first() else {
+        return vec![Span::raw(event.short_name.clone())];
+    };
+    let side = |home_away: &str| comp.competitors.iter().find(|c| c.home_away == home_away);
+    let (Some(a), Some(h)) = (side("away"), side("home")) else {
+        return vec![Span::raw(event.short_name.clone())];
+    };
+
+    let state = &event.status.type_field.state;
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let mut spans = vec![Span::styled(a.team.abbreviation.clone(), bold)];
+    if *state != GameState::Pre {
+        spans.push(Span::raw(format!(" {}", a.score.as_deref().unwrap_or("0"))));
+    }
+    spans.push(Span::raw(" @ "));
+    spans.push(Span::styled(h.team.abbreviation.clone(), bold));
+    if *state != GameState::Pre {
+        spans.push(Span::raw(format!(" {}", h.score.as_deref().unwrap_or("0"))));
+    }
+    let status_color = if *state == GameState::In { Color::Red } else { Color::DarkGray };
+    spans.push(Span::styled(
+        format!(" {}", event.status.type_field.short_detail),
+        Style::default().fg(status_color),
+    ));
+    spans
+}
+
+impl Widget for Ticker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if self.events.is_empty() || area.width == 0 {
+            return;
+        }
+        let mut spans = vec![];
+        for event in &self.events {
+            spans.extend(game_spans(event));
+            spans.push(Span::styled(SEPARATOR, Style::default().fg(Color::DarkGray)));
+        }
+        let cycle = spans.iter().map(|s| s.width()).sum::<usize>().max(1);
+
+        // Repeat the loop until it covers the screen, so the seam never shows
+        let copies = (area.width as usize).div_ceil(cycle) + 1;
+        let line = Line::from(std::iter::repeat_n(spans, copies).flatten().collect::<Vec<_>>());
+        let offset = (self.elapsed_ms * SPEED / 1000) as usize % cycle;
+        Paragraph::new(line)
+            .style(Style::default().bg(Color::Black))
+            .scroll((0, offset as u16))
+            .render(area, buf);
+    }
+}
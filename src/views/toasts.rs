@@ -0,0 +1,38 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// A short-lived message stacked in the top-right corner.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub text: String,
+    pub color: Color,
+    /// The game a score toast is about, so the scoring play can be filled in later.
+    pub event_id: Option<String>,
+}
+
+/// Newest on top; whatever doesn't fit below is left off.
+pub fn draw_toasts<'a>(f: &mut Frame, toasts: impl DoubleEndedIterator<Item = &'a Toast>, area: Rect) {
+    let mut y = area.y + 1;
+    for toast in toasts.rev() {
+        if y + 3 > area.bottom() {
+            break;
+        }
+        let width = (toast.text.chars().count() as u16 + 4).min(area.width / 2).max(10);
+        let rect = Rect {
+            x: area.right().saturating_sub(width + 1),
+            y,
+            width,
+            height: 3,
+        };
+        f.render_widget(Clear, rect);
+        let p = Paragraph::new(toast.text.as_str())
+            .style(Style::default().fg(Color::White))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(toast.color)));
+        f.render_widget(p, rect);
+        y += 3;
+    }
+}